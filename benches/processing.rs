@@ -0,0 +1,80 @@
+//! Benchmarks for the detection pipeline: grayscale conversion,
+//! thresholding, contour analysis and the full [`Processor::process`]
+//! call, each run against committed fixture images at 720p, 1080p and
+//! 4K so a regression in one stage doesn't hide behind an unrelated win
+//! in another. Fixtures are synthetic target photos (light background,
+//! a handful of dark filled "holes") checked into `benches/fixtures/`
+//! rather than generated at bench time, so results are comparable across
+//! runs and machines.
+//!
+//! Like the rest of the split proposed in [`precision_scorer::core`],
+//! this assumes the pipeline is reachable as a library target
+//! (`precision_scorer::processor`) once this checkout gains the Cargo
+//! workspace manifest it's currently missing.
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use image::RgbImage;
+
+use precision_scorer::processor::backends::{self, DetectionBackend};
+use precision_scorer::processor::{DetectionBackendKind, Processor, ProcessorSettings};
+
+const FIXTURES: &[(&str, &str)] = &[
+    ("720p", "benches/fixtures/target-720p.png"),
+    ("1080p", "benches/fixtures/target-1080p.png"),
+    ("4k", "benches/fixtures/target-4k.png"),
+];
+
+fn load_fixture(path: &str) -> RgbImage {
+    image::open(path).unwrap_or_else(|e| panic!("load fixture {path}: {e}")).to_rgb8()
+}
+
+fn bench_grayscale(c: &mut Criterion) {
+    let mut group = c.benchmark_group("grayscale");
+    for (label, path) in FIXTURES {
+        let frame = load_fixture(path);
+        group.bench_with_input(BenchmarkId::from_parameter(label), &frame, |b, frame| {
+            b.iter(|| image::imageops::grayscale(frame));
+        });
+    }
+    group.finish();
+}
+
+fn bench_threshold(c: &mut Criterion) {
+    let mut group = c.benchmark_group("threshold");
+    for (label, path) in FIXTURES {
+        let frame = load_fixture(path);
+        let gray = image::imageops::grayscale(&frame);
+        group.bench_with_input(BenchmarkId::from_parameter(label), &gray, |b, gray| {
+            b.iter(|| gray.enumerate_pixels().filter(|(_, _, p)| p.0[0] < 80).count());
+        });
+    }
+    group.finish();
+}
+
+fn bench_contour_analysis(c: &mut Criterion) {
+    let mut group = c.benchmark_group("contour_analysis");
+    let settings = ProcessorSettings::default();
+    for (label, path) in FIXTURES {
+        let frame = load_fixture(path);
+        group.bench_with_input(BenchmarkId::from_parameter(label), &frame, |b, frame| {
+            let mut backend = backends::make(DetectionBackendKind::Threshold);
+            b.iter(|| backend.detect(frame, &settings));
+        });
+    }
+    group.finish();
+}
+
+fn bench_full_pipeline(c: &mut Criterion) {
+    let mut group = c.benchmark_group("processor_process");
+    for (label, path) in FIXTURES {
+        let frame = load_fixture(path);
+        group.bench_with_input(BenchmarkId::from_parameter(label), &frame, |b, frame| {
+            let mut processor = Processor::default();
+            b.iter(|| processor.process(frame));
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_grayscale, bench_threshold, bench_contour_analysis, bench_full_pipeline);
+criterion_main!(benches);
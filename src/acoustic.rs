@@ -0,0 +1,80 @@
+//! Reads shot coordinates from an acoustic e-target sensor over serial or
+//! TCP and hands them to the caller for fusion with optical detections:
+//! an acoustic reading that arrives close in time to an optical one
+//! confirms it, while one with no optical match stands in for a shot the
+//! camera missed. Assumes a generic wire format shared by both
+//! transports — one ASCII line per shot, `x_mm,y_mm` — since real
+//! acoustic units vary and this is the common denominator most expose
+//! over their configuration serial port.
+
+use std::io::{self, BufRead, BufReader, Read};
+use std::net::TcpStream;
+use std::sync::mpsc::{self, Receiver};
+use std::thread;
+use std::time::Duration;
+
+#[derive(Debug, Clone, Copy)]
+pub struct AcousticShot {
+    pub x_mm: f32,
+    pub y_mm: f32,
+}
+
+pub struct AcousticInputClient {
+    rx: Receiver<AcousticShot>,
+    source: String,
+}
+
+impl AcousticInputClient {
+    pub fn connect_tcp(addr: &str) -> io::Result<Self> {
+        let stream = TcpStream::connect(addr)?;
+        let (tx, rx) = mpsc::channel();
+        thread::spawn(move || read_loop(stream, tx));
+        Ok(Self { rx, source: addr.to_string() })
+    }
+
+    pub fn connect_serial(port_name: &str, baud_rate: u32) -> io::Result<Self> {
+        let port = serialport::new(port_name, baud_rate)
+            .timeout(Duration::from_secs(3600))
+            .open()
+            .map_err(|e| io::Error::new(io::ErrorKind::Other, e.to_string()))?;
+        let (tx, rx) = mpsc::channel();
+        thread::spawn(move || read_loop(port, tx));
+        Ok(Self { rx, source: port_name.to_string() })
+    }
+
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    /// Returns the next buffered acoustic detection, if any, without
+    /// blocking.
+    pub fn poll_shot(&self) -> Option<AcousticShot> {
+        self.rx.try_recv().ok()
+    }
+}
+
+fn read_loop<R: Read>(source: R, tx: mpsc::Sender<AcousticShot>) {
+    let mut reader = BufReader::new(source);
+    let mut line = String::new();
+    loop {
+        line.clear();
+        match reader.read_line(&mut line) {
+            Ok(0) => break,
+            Ok(_) => {
+                if let Some(shot) = parse_line(&line) {
+                    if tx.send(shot).is_err() {
+                        break;
+                    }
+                }
+            }
+            Err(_) => break,
+        }
+    }
+}
+
+fn parse_line(line: &str) -> Option<AcousticShot> {
+    let mut fields = line.trim().split(',');
+    let x_mm: f32 = fields.next()?.parse().ok()?;
+    let y_mm: f32 = fields.next()?.parse().ok()?;
+    Some(AcousticShot { x_mm, y_mm })
+}
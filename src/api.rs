@@ -0,0 +1,196 @@
+//! Optional embedded HTTP server exposing live session state as JSON, so
+//! range-management software can poll scores instead of reading a
+//! printed scorecard. Runs on a background thread; the UI thread just
+//! publishes a fresh snapshot after each frame.
+//!
+//! A range-officer tablet also needs to *drive* the app, not just read
+//! it, so `/control/*` routes accept a bearer token and push an
+//! [`ApiCommand`] onto a channel for the UI thread to drain each frame
+//! via [`ApiServer::poll_command`] — the same "server thread produces,
+//! UI thread applies" split used for read access.
+
+use std::io::Read;
+use std::sync::mpsc::{channel, Receiver, Sender};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+use serde::{Deserialize, Serialize};
+use tiny_http::{Method, Response, Server};
+
+use crate::session::Session;
+
+#[derive(Serialize)]
+struct ScoreResponse {
+    total: f32,
+    x_count: usize,
+}
+
+#[derive(Serialize)]
+struct SessionResponse<'a> {
+    shooter: &'a str,
+    total: f32,
+    x_count: usize,
+    series_count: usize,
+}
+
+#[derive(Deserialize)]
+struct ShooterBody {
+    name: String,
+    club: String,
+}
+
+#[derive(Deserialize)]
+struct TargetBody {
+    preset: String,
+}
+
+/// A control action requested over `/control/*`, applied on the UI
+/// thread since it needs to touch `MyApp` state (session, target,
+/// pause flag) that the server thread doesn't own.
+#[derive(Debug, Clone)]
+pub enum ApiCommand {
+    Freeze,
+    Resume,
+    ResetScorer,
+    SwitchTargetPreset(String),
+    StartNextSeries,
+    ChangeShooter(crate::session::Shooter),
+}
+
+pub struct ApiServer {
+    shared: Arc<Mutex<Session>>,
+    commands: Receiver<ApiCommand>,
+    addr: String,
+}
+
+impl ApiServer {
+    /// Binds `addr` (e.g. `"127.0.0.1:8787"`) and starts serving in a
+    /// background thread. `token` gates every `/control/*` route via
+    /// `Authorization: Bearer <token>`; read-only routes stay open.
+    /// Returns an error immediately if the port can't be bound; failures
+    /// on individual requests are just logged to stderr since nothing
+    /// else polls this server's health.
+    pub fn start(addr: &str, initial: Session, token: String) -> std::io::Result<Self> {
+        let server = Server::http(addr).map_err(|e| {
+            std::io::Error::new(std::io::ErrorKind::AddrInUse, e.to_string())
+        })?;
+        let shared = Arc::new(Mutex::new(initial));
+        let worker_shared = Arc::clone(&shared);
+        let (tx, rx) = channel();
+        thread::spawn(move || serve(server, worker_shared, tx, token));
+        Ok(Self { shared, commands: rx, addr: addr.to_string() })
+    }
+
+    pub fn addr(&self) -> &str {
+        &self.addr
+    }
+
+    /// Publishes the latest session state for the server thread to read.
+    /// Called once per UI frame.
+    pub fn publish(&self, session: &Session) {
+        if let Ok(mut guard) = self.shared.lock() {
+            *guard = session.clone();
+        }
+    }
+
+    /// Non-blocking; drains the next queued control command, if any.
+    pub fn poll_command(&self) -> Option<ApiCommand> {
+        self.commands.try_recv().ok()
+    }
+}
+
+fn serve(server: Server, shared: Arc<Mutex<Session>>, commands: Sender<ApiCommand>, token: String) {
+    for mut request in server.incoming_requests() {
+        let session = shared
+            .lock()
+            .map(|s| s.clone())
+            .unwrap_or_else(|_| Session::new(crate::session::Shooter::default()));
+
+        if request.url().starts_with("/control/") {
+            if !authorized(&request, &token) {
+                let _ = request.respond(Response::from_string("unauthorized").with_status_code(401));
+                continue;
+            }
+            let mut body = String::new();
+            let _ = request.as_reader().read_to_string(&mut body);
+            let command = match request.url() {
+                "/control/freeze" => Some(ApiCommand::Freeze),
+                "/control/resume" => Some(ApiCommand::Resume),
+                "/control/reset" => Some(ApiCommand::ResetScorer),
+                "/control/next-series" => Some(ApiCommand::StartNextSeries),
+                "/control/target" => serde_json::from_str::<TargetBody>(&body)
+                    .ok()
+                    .map(|t| ApiCommand::SwitchTargetPreset(t.preset)),
+                "/control/shooter" => serde_json::from_str::<ShooterBody>(&body)
+                    .ok()
+                    .map(|s| ApiCommand::ChangeShooter(crate::session::Shooter { name: s.name, club: s.club })),
+                _ => None,
+            };
+            let response = match command {
+                Some(command) => {
+                    let _ = commands.send(command);
+                    Response::from_string("ok")
+                }
+                None => Response::from_string("bad request").with_status_code(400),
+            };
+            if let Err(e) = request.respond(response) {
+                tracing::warn!(error = %e, "api control request failed");
+            }
+            continue;
+        }
+
+        let body = match request.url() {
+            "/session" => serde_json::to_string(&SessionResponse {
+                shooter: &session.shooter.name,
+                total: session.total(),
+                x_count: session.x_count(),
+                series_count: session.series.len(),
+            }),
+            "/score" => serde_json::to_string(&ScoreResponse {
+                total: session.total(),
+                x_count: session.x_count(),
+            }),
+            "/shots" => serde_json::to_string(&session.all_shots().collect::<Vec<_>>()),
+            _ => {
+                let _ = request.respond(Response::from_string("not found").with_status_code(404));
+                continue;
+            }
+        };
+        let response = match body {
+            Ok(json) => Response::from_string(json)
+                .with_header("Content-Type: application/json".parse().unwrap()),
+            Err(e) => Response::from_string(format!("serialization error: {e}")).with_status_code(500),
+        };
+        if let Err(e) = request.respond(response) {
+            tracing::warn!(error = %e, "api request failed");
+        }
+    }
+}
+
+/// A route is authorized only if `token` is non-empty and the request is
+/// a POST carrying a matching `Authorization: Bearer <token>` header; an
+/// empty `token` disables the control surface entirely (every request is
+/// rejected) rather than granting free access.
+fn authorized(request: &tiny_http::Request, token: &str) -> bool {
+    if token.is_empty() {
+        return false;
+    }
+    if *request.method() != Method::Post {
+        return false;
+    }
+    let expected = format!("Bearer {token}");
+    request.headers().iter().any(|h| {
+        h.field.as_str().as_str().eq_ignore_ascii_case("Authorization")
+            && constant_time_eq(h.value.as_str(), &expected)
+    })
+}
+
+/// Compares `a` and `b` in time independent of where they first differ,
+/// so a bearer-token check can't be brute-forced byte-by-byte via
+/// response-timing measurements the way a short-circuiting `==` can.
+fn constant_time_eq(a: &str, b: &str) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    a.bytes().zip(b.bytes()).fold(0u8, |diff, (x, y)| diff | (x ^ y)) == 0
+}
@@ -0,0 +1,2349 @@
+//! Top-level eframe application: owns the current session and wires the
+//! UI panels together.
+
+use std::collections::VecDeque;
+
+use crate::calibration::ScoringConfig;
+use crate::camera::Camera;
+use crate::export;
+use crate::notify::NotificationCenter;
+use crate::processor::{Processor, ProcessorSettings};
+use crate::session::{Series, Session, Shooter};
+use crate::target::{self, TargetType};
+use crate::timer::{MatchPhase, MatchTimer, TimerConfig};
+use crate::undo::{ReplaceSession, UndoStack};
+
+use image::RgbImage;
+
+/// How many recent frames [`MyApp::recent_diagnostic_frames`] keeps around
+/// for "Export diagnostics".
+const DIAGNOSTIC_FRAME_HISTORY: usize = 5;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ViewMode {
+    #[default]
+    Normal,
+    FrameDiff,
+}
+
+/// Whether new shots are currently being ingested from the live pipeline.
+///
+/// This is the one piece of app-wide modal state in this tree today —
+/// [`MyApp::view_mode`], the replay window and the static-image tabs are
+/// independent overlays a user can have open regardless of scoring state,
+/// not alternatives to it, so they stay as separate fields rather than
+/// being folded into this enum.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ScoringMode {
+    #[default]
+    Live,
+    /// Set by a remote `/control/freeze`; suspends new-shot ingestion
+    /// while the UI keeps rendering, e.g. so a range officer can pause
+    /// every lane at once.
+    Frozen,
+}
+
+pub struct MyApp {
+    pub session: Session,
+    pub target: TargetType,
+    /// Other shooter positions running in this instance; see
+    /// [`crate::lane`]. The fields above always mirror `lanes`' active
+    /// lane while it's live.
+    pub lanes: crate::lane::LaneManager,
+    /// Toggles the lane strip between the compact name-only switcher and
+    /// [`crate::lane::LaneManager::show_tiled_overview`]'s per-lane score
+    /// grid.
+    pub lane_tiled_overview: bool,
+    pub notifications: NotificationCenter,
+    pub show_notification_history: bool,
+    /// Most recently processed frame, used for burned-in exports. `None`
+    /// until a source (camera or static image) has produced one.
+    pub current_frame: Option<RgbImage>,
+    /// Grayscale + crop result for the histogram/threshold panel, cached
+    /// against `cached_roi_key` so a threshold slider drag doesn't
+    /// re-grayscale and re-crop the full frame every frame; only
+    /// invalidated when the frame content or the crop region changes.
+    cached_roi: Option<image::GrayImage>,
+    cached_roi_key: Option<(u64, Option<(u32, u32, u32, u32)>)>,
+    pub center_px: (f32, f32),
+    pub pixels_per_mm: f32,
+    pub match_timer: Option<MatchTimer>,
+    pub timer_config: TimerConfig,
+    /// Degrees to rotate the overlay (and virtual target view) so plotted
+    /// clock positions match a target print that isn't perfectly aligned
+    /// with the camera.
+    pub overlay_rotation_deg: f32,
+    pub compare_view: crate::ui::compare::CompareView,
+    pub show_compare_window: bool,
+    pub replay: crate::ui::replay::ReplayState,
+    pub show_replay_window: bool,
+    pub image_view: crate::ui::image_view::ImageView,
+    pub touch_mode: crate::ui::touch_mode::TouchModeConfig,
+    pub calibration_wizard: Option<crate::ui::calibration_wizard::CalibrationWizard>,
+    pub camera: Option<Camera>,
+    pub processor: Processor,
+    /// Capture→preprocess→detect→score→render staged pipeline feeding
+    /// [`Self::recent_diagnostic_frames`]; see [`crate::pipeline`].
+    frame_pipeline: crate::pipeline::FramePipeline,
+    pipeline_config: std::sync::Arc<crate::pipeline::PipelineConfig>,
+    pub static_tabs: crate::ui::tabs::TabbedImages,
+    /// Path of the most recently opened static image, if any; recorded
+    /// only so "Save project" can remember where its frames came from.
+    last_opened_image_path: Option<std::path::PathBuf>,
+    autosave_timer: crate::recovery::AutosaveTimer,
+    /// Left behind by an unclean exit, offered to the operator as a
+    /// "Restore previous session?" prompt on startup; `None` once
+    /// dismissed or restored.
+    pending_recovery: Option<crate::project::ProjectFile>,
+    pub settings: crate::settings::Settings,
+    /// Official gauge (caliber) diameter in mm, drawn around the selected
+    /// shot to settle ring-line disputes the way manual scoring does.
+    pub gauge_diameter_mm: f32,
+    pub view_mode: ViewMode,
+    /// Stable/reference frame the current frame is compared against in
+    /// `ViewMode::FrameDiff`.
+    pub reference_frame: Option<RgbImage>,
+    pub two_point_calibrate: crate::ui::calibrate_two_point::TwoPointCalibrateTool,
+    pub measure_tool: crate::ui::measure_tool::MeasureTool,
+    pub show_overlay_settings: bool,
+    pub show_theme_settings: bool,
+    /// Toggles the puffin profiler window; scope recording (see the
+    /// `puffin::profile_scope!` calls around capture/crop/process/score/
+    /// paint) is only turned on while this is open, so idle sessions pay
+    /// no profiling overhead.
+    pub show_profiler_window: bool,
+    pub shot_list: crate::ui::shot_list::ShotListState,
+    pub undo_stack: UndoStack,
+    /// Set once the user starts the live-scores API server; `None` means
+    /// it isn't running.
+    pub api_server: Option<crate::api::ApiServer>,
+    pub api_addr: String,
+    /// Bearer token required on `/control/*` routes; empty disables them.
+    pub api_token: String,
+    pub scoring_mode: ScoringMode,
+    pub shot_broadcaster: Option<crate::ws::ShotBroadcaster>,
+    pub ws_addr: String,
+    /// Shot count as of the last broadcast, so only newly-added shots are
+    /// pushed to WebSocket clients.
+    last_broadcast_shot_count: usize,
+    pub udp_broadcaster: Option<crate::udp::UdpBroadcaster>,
+    pub udp_broadcast_addr: String,
+    /// This station's lane number, tagged onto every UDP packet so a
+    /// master scoreboard can tell lanes apart.
+    pub udp_lane: u16,
+    last_udp_shot_count: usize,
+    pub csv_feed: Option<crate::csv_feed::CsvFeed>,
+    last_csv_shot_count: usize,
+    pub show_webhook_settings: bool,
+    pub show_shot_trigger_settings: bool,
+    pub show_units_settings: bool,
+    last_shot_trigger_shot_count: usize,
+    pub lane_config_watcher: Option<crate::lane_config::HotReloadWatcher>,
+    pub lane_config_path: String,
+    /// Runs `on_shot`/`on_series_complete`/`on_session_end` hooks; see
+    /// [`crate::scripting`]. `on_series_complete`/`on_session_end` are
+    /// still called directly since those events happen at one obvious
+    /// call site each; `on_shot` is fed from `event_bus` instead (see
+    /// `script_event_rx`), as the first consumer of the new bus.
+    script_engine: Option<crate::scripting::ScriptEngine>,
+    pub script_path: String,
+    /// Subscribed to `event_bus` when a script is loaded, so the script
+    /// only sees shots scored from that point on.
+    script_event_rx: Option<std::sync::mpsc::Receiver<crate::events::AppEvent>>,
+    /// Typed event bus; see [`crate::events`].
+    event_bus: crate::events::EventBus,
+    /// Captures every frame plus every bus event to `recording_path`
+    /// while `Some`; see [`crate::replay`].
+    recorder: Option<crate::replay::Recorder>,
+    recorder_event_rx: Option<std::sync::mpsc::Receiver<crate::events::AppEvent>>,
+    pub recording_path: String,
+    /// Feeds recorded frames back into `current_frame` one per update
+    /// while `Some` and `replay_playing`.
+    replay_player: Option<crate::replay::Player>,
+    replay_playing: bool,
+    pub replay_path: String,
+    last_event_shot_count: usize,
+    last_published_calibration: Option<ScoringConfig>,
+    last_published_processor_settings: Option<crate::processor::ProcessorSettings>,
+    /// Last few processed frames with their detections, kept only for
+    /// "Export diagnostics"; capped at [`DIAGNOSTIC_FRAME_HISTORY`].
+    recent_diagnostic_frames: VecDeque<crate::export::diagnostics::DiagnosticFrame>,
+    pub league_csv_config: crate::export::league_csv::LeagueCsvConfig,
+    pub show_league_csv_settings: bool,
+    pub led_scoreboard: Option<crate::led_scoreboard::LedScoreboardOutput>,
+    pub led_scoreboard_config: crate::led_scoreboard::LedScoreboardConfig,
+    pub show_led_scoreboard_settings: bool,
+    last_led_shot_count: usize,
+    pub mjpeg_server: Option<crate::mjpeg::MjpegServer>,
+    pub mjpeg_addr: String,
+    pub remote_frame_sender: Option<crate::remote_camera::FrameSender>,
+    pub remote_frame_sender_addr: String,
+    pub remote_frame_receiver: Option<crate::remote_camera::FrameReceiver>,
+    pub remote_frame_receiver_addr: String,
+    pub shot_timer: Option<crate::shot_timer::ShotTimerClient>,
+    pub shot_timer_port: String,
+    /// Splits received but not yet matched to a detected hole, oldest
+    /// first.
+    pending_timer_splits: VecDeque<f32>,
+    last_timer_matched_shot_count: usize,
+    pub acoustic_client: Option<crate::acoustic::AcousticInputClient>,
+    pub acoustic_addr: String,
+    /// Acoustic detections not yet confirmed against an optical shot or
+    /// promoted to their own shot, with when they arrived.
+    pending_acoustic: Vec<(crate::acoustic::AcousticShot, std::time::Instant)>,
+    last_acoustic_matched_shot_count: usize,
+    pub mqtt_publisher: Option<crate::mqtt::MqttPublisher>,
+    pub show_mqtt_settings: bool,
+    /// Shot count as of the last MQTT publish.
+    last_mqtt_shot_count: usize,
+    pub stream_overlay: Option<crate::stream_overlay::StreamOverlayServer>,
+    pub stream_overlay_addr: String,
+    pub show_sync_settings: bool,
+    pub history_browser: crate::ui::history_browser::HistoryBrowser,
+    pub show_history_window: bool,
+    /// Session opened read-only from the history browser, viewed
+    /// alongside (not replacing) the active one.
+    pub viewed_history_session: Option<Session>,
+    pub show_companion_window: bool,
+    /// Cached so the QR texture is only regenerated when the mobile URL
+    /// changes (e.g. the overlay server restarts on a new address).
+    companion_qr_url: String,
+    companion_qr_texture: Option<egui::TextureHandle>,
+}
+
+impl Default for MyApp {
+    fn default() -> Self {
+        let pipeline_config = crate::pipeline::PipelineConfig::new(
+            ProcessorSettings::default(),
+            ScoringConfig::default(),
+            target::issf_10m(),
+        );
+        Self {
+            session: Session::new(Shooter::default()),
+            target: target::issf_10m(),
+            lanes: crate::lane::LaneManager::new(crate::lane::Lane::new("Lane 1".to_string(), target::issf_10m())),
+            lane_tiled_overview: false,
+            notifications: Default::default(),
+            show_notification_history: false,
+            current_frame: None,
+            cached_roi: None,
+            cached_roi_key: None,
+            center_px: (0.0, 0.0),
+            pixels_per_mm: 1.0,
+            match_timer: None,
+            timer_config: TimerConfig::default(),
+            overlay_rotation_deg: 0.0,
+            compare_view: Default::default(),
+            show_compare_window: false,
+            replay: Default::default(),
+            show_replay_window: false,
+            image_view: Default::default(),
+            touch_mode: Default::default(),
+            calibration_wizard: None,
+            camera: None,
+            processor: Processor::default(),
+            frame_pipeline: crate::pipeline::FramePipeline::start(pipeline_config.clone()),
+            pipeline_config,
+            static_tabs: Default::default(),
+            last_opened_image_path: None,
+            autosave_timer: crate::recovery::AutosaveTimer::default(),
+            pending_recovery: crate::recovery::load_pending(),
+            settings: crate::settings::Settings::load(),
+            gauge_diameter_mm: 4.5,
+            view_mode: ViewMode::default(),
+            reference_frame: None,
+            two_point_calibrate: Default::default(),
+            measure_tool: Default::default(),
+            show_overlay_settings: false,
+            show_theme_settings: false,
+            show_profiler_window: false,
+            shot_list: Default::default(),
+            undo_stack: Default::default(),
+            api_server: None,
+            api_addr: "127.0.0.1:8787".to_string(),
+            api_token: String::new(),
+            scoring_mode: ScoringMode::Live,
+            shot_broadcaster: None,
+            ws_addr: "127.0.0.1:8788".to_string(),
+            last_broadcast_shot_count: 0,
+            udp_broadcaster: None,
+            udp_broadcast_addr: "255.255.255.255:8790".to_string(),
+            udp_lane: 1,
+            last_udp_shot_count: 0,
+            csv_feed: None,
+            last_csv_shot_count: 0,
+            show_webhook_settings: false,
+            show_shot_trigger_settings: false,
+            show_units_settings: false,
+            last_shot_trigger_shot_count: 0,
+            lane_config_watcher: None,
+            lane_config_path: String::new(),
+            script_engine: None,
+            script_path: String::new(),
+            script_event_rx: None,
+            event_bus: crate::events::EventBus::default(),
+            recorder: None,
+            recorder_event_rx: None,
+            recording_path: String::new(),
+            replay_player: None,
+            replay_playing: false,
+            replay_path: String::new(),
+            last_event_shot_count: 0,
+            last_published_calibration: None,
+            last_published_processor_settings: None,
+            recent_diagnostic_frames: VecDeque::new(),
+            league_csv_config: Default::default(),
+            show_league_csv_settings: false,
+            led_scoreboard: None,
+            led_scoreboard_config: Default::default(),
+            show_led_scoreboard_settings: false,
+            last_led_shot_count: 0,
+            mjpeg_server: None,
+            mjpeg_addr: "127.0.0.1:8791".to_string(),
+            remote_frame_sender: None,
+            remote_frame_sender_addr: "0.0.0.0:8792".to_string(),
+            remote_frame_receiver: None,
+            remote_frame_receiver_addr: "127.0.0.1:8792".to_string(),
+            shot_timer: None,
+            shot_timer_port: String::new(),
+            pending_timer_splits: VecDeque::new(),
+            last_timer_matched_shot_count: 0,
+            acoustic_client: None,
+            acoustic_addr: String::new(),
+            pending_acoustic: Vec::new(),
+            last_acoustic_matched_shot_count: 0,
+            mqtt_publisher: None,
+            show_mqtt_settings: false,
+            last_mqtt_shot_count: 0,
+            stream_overlay: None,
+            stream_overlay_addr: "127.0.0.1:8789".to_string(),
+            show_sync_settings: false,
+            history_browser: Default::default(),
+            show_history_window: false,
+            viewed_history_session: None,
+            show_companion_window: false,
+            companion_qr_url: String::new(),
+            companion_qr_texture: None,
+        }
+    }
+}
+
+impl MyApp {
+    pub fn scoring_config(&self) -> ScoringConfig {
+        ScoringConfig {
+            center_px: self.center_px,
+            pixels_per_mm: self.pixels_per_mm,
+            rotation_deg: self.overlay_rotation_deg,
+        }
+    }
+
+    /// Loads any files dropped onto the window: images become new static
+    /// tabs, video files are queued for the batch/video pipeline.
+    /// Draws the overlay-appearance window, if open. Returns true if any
+    /// value changed and settings should be persisted.
+    fn show_overlay_window(&mut self, ctx: &egui::Context) -> bool {
+        if !self.show_overlay_settings {
+            return false;
+        }
+        let mut changed = false;
+        let overlay = &mut self.settings.overlay;
+        egui::Window::new("Overlay colors").open(&mut self.show_overlay_settings).show(ctx, |ui| {
+            changed |= color_picker(ui, "10/9 ring", &mut overlay.zone_palette.high);
+            changed |= color_picker(ui, "8/7 ring", &mut overlay.zone_palette.mid);
+            changed |= color_picker(ui, "below 7", &mut overlay.zone_palette.low);
+            changed |= color_picker(ui, "Center marker", &mut overlay.center_color);
+            changed |= color_picker(ui, "Labels", &mut overlay.label_color);
+            changed |= ui
+                .add(egui::Slider::new(&mut overlay.hole_opacity, 0.0..=1.0).text("Hole opacity"))
+                .changed();
+            changed |= ui
+                .add(egui::Slider::new(&mut overlay.hole_line_width, 0.5..=6.0).text("Hole line width"))
+                .changed();
+            changed |= ui
+                .add(egui::Slider::new(&mut overlay.ring_line_width, 0.5..=6.0).text("Ring line width"))
+                .changed();
+        });
+        changed
+    }
+
+    /// Draws the mobile companion window, if open: a QR code linking to
+    /// the running/streaming overlay server's `/mobile` page, so a coach
+    /// can join by scanning it. Requires the OBS overlay server to be
+    /// started, since it hosts the companion page.
+    fn show_companion_window(&mut self, ctx: &egui::Context) {
+        if !self.show_companion_window {
+            return;
+        }
+        egui::Window::new("Mobile companion").open(&mut self.show_companion_window).show(ctx, |ui| {
+            let Some(overlay) = &self.stream_overlay else {
+                ui.label("Start the OBS overlay server first — it also hosts the mobile page.");
+                return;
+            };
+            let url = overlay.mobile_url();
+            ui.label(&url);
+            if self.companion_qr_texture.is_none() || self.companion_qr_url != url {
+                match crate::qr::encode(&url, 6) {
+                    Ok(qr) => {
+                        self.companion_qr_texture = Some(ui.ctx().load_texture(
+                            "companion_qr",
+                            qr.to_color_image(),
+                            Default::default(),
+                        ));
+                        self.companion_qr_url = url;
+                    }
+                    Err(e) => {
+                        ui.label(format!("failed to render QR code: {e}"));
+                    }
+                }
+            }
+            if let Some(texture) = &self.companion_qr_texture {
+                ui.image((texture.id(), texture.size_vec2()));
+            }
+        });
+    }
+
+    /// Draws the theme window, if open. Returns true if any value changed
+    /// and settings should be persisted.
+    fn show_theme_window(&mut self, ctx: &egui::Context) -> bool {
+        if !self.show_theme_settings {
+            return false;
+        }
+        let mut changed = false;
+        let theme = &mut self.settings.theme;
+        egui::Window::new("Theme").open(&mut self.show_theme_settings).show(ctx, |ui| {
+            changed |= ui
+                .radio_value(&mut theme.theme, crate::ui::theme::Theme::Light, "Light")
+                .changed();
+            changed |= ui
+                .radio_value(&mut theme.theme, crate::ui::theme::Theme::Dark, "Dark")
+                .changed();
+            changed |= ui
+                .radio_value(
+                    &mut theme.theme,
+                    crate::ui::theme::Theme::HighContrast,
+                    "High contrast (range TV)",
+                )
+                .changed();
+            changed |= color_picker(ui, "Accent color", &mut theme.accent_color);
+        });
+        changed
+    }
+
+    /// Draws the MQTT connection window, if open. Returns true if any
+    /// value changed and settings should be persisted.
+    fn show_mqtt_window(&mut self, ctx: &egui::Context) -> bool {
+        if !self.show_mqtt_settings {
+            return false;
+        }
+        let mut changed = false;
+        let mqtt = &mut self.settings.mqtt;
+        let mut connect_clicked = false;
+        egui::Window::new("MQTT").open(&mut self.show_mqtt_settings).show(ctx, |ui| {
+            changed |= ui.text_edit_singleline(&mut mqtt.broker_host).changed();
+            changed |= ui
+                .add(egui::DragValue::new(&mut mqtt.broker_port).range(1..=65535))
+                .changed();
+            changed |= ui.text_edit_singleline(&mut mqtt.username).changed();
+            changed |= ui.add(egui::TextEdit::singleline(&mut mqtt.password).password(true)).changed();
+            changed |= ui.text_edit_singleline(&mut mqtt.topic_prefix).changed();
+            connect_clicked = ui.button("Connect").clicked();
+        });
+        if connect_clicked {
+            self.mqtt_publisher = Some(crate::mqtt::MqttPublisher::connect(&self.settings.mqtt));
+            self.last_mqtt_shot_count = self.session.all_shots().count();
+            self.notifications.info(format!(
+                "Connecting to MQTT broker {}:{}",
+                self.settings.mqtt.broker_host, self.settings.mqtt.broker_port
+            ));
+        }
+        changed
+    }
+
+    /// Draws the cloud sync settings window, if open. Returns true if any
+    /// value changed and settings should be persisted.
+    fn show_sync_window(&mut self, ctx: &egui::Context) -> bool {
+        if !self.show_sync_settings {
+            return false;
+        }
+        let mut changed = false;
+        let sync = &mut self.settings.sync;
+        egui::Window::new("Cloud sync").open(&mut self.show_sync_settings).show(ctx, |ui| {
+            changed |= ui
+                .radio_value(&mut sync.backend, crate::sync::SyncBackend::Disabled, "Disabled")
+                .changed();
+            changed |= ui
+                .radio_value(&mut sync.backend, crate::sync::SyncBackend::WebDav, "WebDAV")
+                .changed();
+            changed |= ui
+                .radio_value(&mut sync.backend, crate::sync::SyncBackend::GoogleSheets, "Google Sheets")
+                .changed();
+            match sync.backend {
+                crate::sync::SyncBackend::WebDav => {
+                    changed |= ui.text_edit_singleline(&mut sync.webdav_url).changed();
+                    changed |= ui.text_edit_singleline(&mut sync.webdav_username).changed();
+                    changed |= ui
+                        .add(egui::TextEdit::singleline(&mut sync.webdav_password).password(true))
+                        .changed();
+                }
+                crate::sync::SyncBackend::GoogleSheets => {
+                    changed |= ui.text_edit_singleline(&mut sync.google_sheets_id).changed();
+                    changed |= ui.text_edit_singleline(&mut sync.google_sheets_api_key).changed();
+                }
+                crate::sync::SyncBackend::Disabled => {}
+            }
+        });
+        changed
+    }
+
+    /// Draws the milestone webhook settings window, if open. Returns true
+    /// if any value changed and settings should be persisted.
+    fn show_webhook_window(&mut self, ctx: &egui::Context) -> bool {
+        if !self.show_webhook_settings {
+            return false;
+        }
+        let mut changed = false;
+        let webhook = &mut self.settings.webhook;
+        egui::Window::new("Milestone webhook").open(&mut self.show_webhook_settings).show(ctx, |ui| {
+            changed |= ui
+                .radio_value(&mut webhook.kind, crate::webhook::WebhookKind::Disabled, "Disabled")
+                .changed();
+            changed |= ui
+                .radio_value(&mut webhook.kind, crate::webhook::WebhookKind::Discord, "Discord")
+                .changed();
+            changed |= ui
+                .radio_value(&mut webhook.kind, crate::webhook::WebhookKind::Telegram, "Telegram")
+                .changed();
+            changed |= ui
+                .radio_value(&mut webhook.kind, crate::webhook::WebhookKind::Generic, "Generic HTTP")
+                .changed();
+            match webhook.kind {
+                crate::webhook::WebhookKind::Discord | crate::webhook::WebhookKind::Generic => {
+                    ui.label("Webhook URL:");
+                    changed |= ui.text_edit_singleline(&mut webhook.url).changed();
+                }
+                crate::webhook::WebhookKind::Telegram => {
+                    ui.label("Bot token:");
+                    changed |= ui
+                        .add(egui::TextEdit::singleline(&mut webhook.telegram_bot_token).password(true))
+                        .changed();
+                    ui.label("Chat ID:");
+                    changed |= ui.text_edit_singleline(&mut webhook.telegram_chat_id).changed();
+                }
+                crate::webhook::WebhookKind::Disabled => {}
+            }
+        });
+        changed
+    }
+
+    /// Draws the per-shot home-automation trigger window, if open.
+    fn show_shot_trigger_window(&mut self, ctx: &egui::Context) -> bool {
+        if !self.show_shot_trigger_settings {
+            return false;
+        }
+        let mut changed = false;
+        let trigger = &mut self.settings.shot_trigger;
+        egui::Window::new("Shot trigger").open(&mut self.show_shot_trigger_settings).show(ctx, |ui| {
+            changed |= ui.checkbox(&mut trigger.enabled, "Enabled").changed();
+            ui.label("URL:");
+            changed |= ui.text_edit_singleline(&mut trigger.url).changed();
+            ui.label("Body template ({number}, {value}, {is_x}, {x_mm}, {y_mm}):");
+            changed |= ui.text_edit_multiline(&mut trigger.body_template).changed();
+        });
+        changed
+    }
+
+    /// Draws the unit system settings window, if open.
+    fn show_units_window(&mut self, ctx: &egui::Context) -> bool {
+        if !self.show_units_settings {
+            return false;
+        }
+        let mut changed = false;
+        let units = &mut self.settings.units;
+        egui::Window::new("Units").open(&mut self.show_units_settings).show(ctx, |ui| {
+            changed |= ui
+                .radio_value(&mut units.system, crate::units::UnitSystem::Metric, "Metric (mm)")
+                .changed();
+            changed |= ui
+                .radio_value(&mut units.system, crate::units::UnitSystem::Imperial, "Imperial (in)")
+                .changed();
+            ui.separator();
+            ui.label("Target distance (0 disables MOA):");
+            changed |= ui
+                .add(egui::Slider::new(&mut units.target_distance_m, 0.0..=1000.0).text("meters"))
+                .changed();
+        });
+        changed
+    }
+
+    /// Draws the league CSV column-mapping window, if open.
+    fn show_league_csv_window(&mut self, ctx: &egui::Context) {
+        if !self.show_league_csv_settings {
+            return;
+        }
+        let config = &mut self.league_csv_config;
+        let mut export_clicked = false;
+        egui::Window::new("League CSV columns").open(&mut self.show_league_csv_settings).show(ctx, |ui| {
+            ui.label("Relay/squad:");
+            ui.text_edit_singleline(&mut config.relay);
+            ui.separator();
+            for column in crate::export::league_csv::ALL_COLUMNS {
+                let mut included = config.columns.contains(&column);
+                if ui.checkbox(&mut included, column.header()).changed() {
+                    if included {
+                        config.columns.push(column);
+                    } else {
+                        config.columns.retain(|c| *c != column);
+                    }
+                }
+            }
+            ui.separator();
+            export_clicked = ui.button("Export…").clicked();
+        });
+        if export_clicked {
+            self.export_league_csv();
+        }
+    }
+
+    /// Draws the LED scoreboard connection window, if open.
+    fn show_led_scoreboard_window(&mut self, ctx: &egui::Context) {
+        if !self.show_led_scoreboard_settings {
+            return;
+        }
+        let config = &mut self.led_scoreboard_config;
+        let mut connect_clicked = false;
+        egui::Window::new("LED scoreboard").open(&mut self.show_led_scoreboard_settings).show(
+            ctx,
+            |ui| {
+                ui.label("Serial port:");
+                ui.text_edit_singleline(&mut config.port_name);
+                ui.label("Baud rate:");
+                ui.add(egui::DragValue::new(&mut config.baud_rate).range(300..=921_600));
+                ui.label("RS-485 address:");
+                ui.add(egui::DragValue::new(&mut config.address).range(0..=255));
+                ui.label("Frame template ({addr}, {total}, {last}):");
+                ui.text_edit_singleline(&mut config.template);
+                connect_clicked = ui.button("Connect").clicked();
+            },
+        );
+        if connect_clicked {
+            match crate::led_scoreboard::LedScoreboardOutput::connect(self.led_scoreboard_config.clone()) {
+                Ok(output) => {
+                    self.notifications.info(format!("LED scoreboard connected on {}", output.port_name()));
+                    self.last_led_shot_count = self.session.all_shots().count();
+                    self.led_scoreboard = Some(output);
+                }
+                Err(e) => self.notifications.error(format!("connect LED scoreboard: {e}")),
+            }
+        }
+    }
+
+    fn export_league_csv(&mut self) {
+        let Some(path) = rfd::FileDialog::new()
+            .add_filter("CSV", &["csv"])
+            .set_file_name("league-results.csv")
+            .save_file()
+        else {
+            return;
+        };
+        match export::league_csv::write_league_csv(
+            &self.session,
+            &self.target,
+            &self.league_csv_config,
+            &path,
+        ) {
+            Ok(()) => self.notifications.info(format!("Saved {}", path.display())),
+            Err(e) => self.notifications.error(format!("export league CSV: {e}")),
+        }
+    }
+
+    /// Renders the current frame with scoring overlays and PNG-encodes it,
+    /// for attaching to a milestone webhook post.
+    fn render_annotated_png(&self) -> Option<Vec<u8>> {
+        let frame = self.current_frame.as_ref()?;
+        let annotated = export::image::render_annotated(
+            frame,
+            self.center_px,
+            self.pixels_per_mm,
+            self.overlay_rotation_deg,
+            &self.session,
+            &self.target,
+            &self.settings.overlay,
+        );
+        use image::ImageEncoder;
+        let mut png = Vec::new();
+        image::codecs::png::PngEncoder::new(&mut png)
+            .write_image(annotated.as_raw(), annotated.width(), annotated.height(), image::ColorType::Rgb8)
+            .ok()?;
+        Some(png)
+    }
+
+    /// Posts a milestone notification in the background so the UI doesn't
+    /// stall on network I/O; failures are logged via `tracing` since
+    /// there's no channel back to the notification center from that thread.
+    fn post_webhook_milestone(&self, message: String) {
+        let config = self.settings.webhook.clone();
+        let image_png = self.render_annotated_png().unwrap_or_default();
+        crate::integrations::post_webhook_milestone(config, message, image_png);
+    }
+
+    /// Drains any splits reported by the shot timer and attaches the
+    /// oldest unmatched one to each newly detected hole, in order, so
+    /// time and accuracy stay paired even if several shots land before
+    /// this runs.
+    fn match_timer_splits(&mut self) {
+        let Some(client) = &self.shot_timer else { return };
+        while let Some(split) = client.poll_split() {
+            self.pending_timer_splits.push_back(split);
+        }
+        let shot_count = self.session.all_shots().count();
+        if shot_count <= self.last_timer_matched_shot_count || self.pending_timer_splits.is_empty() {
+            self.last_timer_matched_shot_count = shot_count;
+            return;
+        }
+        let mut updated = self.session.clone();
+        let matched =
+            attach_timer_splits(&mut updated, self.last_timer_matched_shot_count, &mut self.pending_timer_splits);
+        self.last_timer_matched_shot_count = shot_count;
+        if matched {
+            self.apply_session_change("Attach timer split", updated);
+        }
+    }
+
+    /// How close in time an acoustic detection and an optical detection
+    /// have to be to count as the same shot.
+    const ACOUSTIC_CONFIRM_WINDOW: std::time::Duration = std::time::Duration::from_millis(1500);
+
+    /// Fuses buffered acoustic detections with optical ones: a detection
+    /// that arrives within the confirmation window of a newly-detected
+    /// hole confirms it, while one that ages out unmatched is promoted to
+    /// its own shot, scored from its reported coordinates, so a difficult
+    /// card the camera missed is still recorded.
+    fn fuse_acoustic_input(&mut self) {
+        let Some(client) = &self.acoustic_client else { return };
+        while let Some(shot) = client.poll_shot() {
+            self.pending_acoustic.push((shot, std::time::Instant::now()));
+        }
+
+        let shot_count = self.session.all_shots().count();
+        if shot_count > self.last_acoustic_matched_shot_count {
+            let new_numbers: Vec<usize> = self
+                .session
+                .all_shots()
+                .skip(self.last_acoustic_matched_shot_count)
+                .map(|s| s.number)
+                .collect();
+            let mut updated = self.session.clone();
+            let mut confirmed_any = false;
+            for number in new_numbers {
+                if let Some(pos) = self
+                    .pending_acoustic
+                    .iter()
+                    .position(|(_, t)| t.elapsed() < Self::ACOUSTIC_CONFIRM_WINDOW)
+                {
+                    self.pending_acoustic.remove(pos);
+                    if let Some(shot) =
+                        updated.series.iter_mut().flat_map(|s| &mut s.shots).find(|s| s.number == number)
+                    {
+                        shot.acoustic_confirmed = true;
+                        confirmed_any = true;
+                    }
+                }
+            }
+            self.last_acoustic_matched_shot_count = shot_count;
+            if confirmed_any {
+                self.apply_session_change("Confirm acoustic detection", updated);
+            }
+        }
+
+        let (stale, fresh): (Vec<_>, Vec<_>) = self
+            .pending_acoustic
+            .drain(..)
+            .partition(|(_, t)| t.elapsed() >= Self::ACOUSTIC_CONFIRM_WINDOW);
+        self.pending_acoustic = fresh;
+        if stale.is_empty() {
+            return;
+        }
+
+        let mut updated = self.session.clone();
+        let detections = stale.into_iter().map(|(detection, _)| detection).collect();
+        promote_unconfirmed_acoustic_shots(&mut updated, &self.target, detections);
+        self.last_acoustic_matched_shot_count = updated.all_shots().count();
+        self.apply_session_change("Acoustic-only detection", updated);
+    }
+
+    /// Uploads the current session in the background so the UI doesn't
+    /// stall on network I/O; failures are logged via `tracing` since
+    /// there's no channel back to the notification center from that thread.
+    fn sync_current_session(&mut self) {
+        let config = self.settings.sync.clone();
+        let session = self.session.clone();
+        std::thread::spawn(move || {
+            if let Err(e) = crate::sync::sync_session(&config, &session) {
+                tracing::warn!(error = %e, "cloud sync failed");
+            }
+        });
+        self.notifications.info("Syncing session to cloud…");
+    }
+
+    fn handle_dropped_files(&mut self, ctx: &egui::Context) {
+        let dropped = ctx.input(|i| i.raw.dropped_files.clone());
+        for file in dropped {
+            let Some(path) = file.path else { continue };
+            match path.extension().and_then(|e| e.to_str()).unwrap_or("").to_lowercase().as_str() {
+                "png" | "jpg" | "jpeg" | "bmp" => match image::open(&path) {
+                    Ok(img) => {
+                        let title = path
+                            .file_name()
+                            .map(|n| n.to_string_lossy().to_string())
+                            .unwrap_or_else(|| "image".to_string());
+                        self.static_tabs.open(title, img.to_rgb8());
+                        self.last_opened_image_path = Some(path.clone());
+                    }
+                    Err(e) => self.notifications.error(format!("load dropped image: {e}")),
+                },
+                "mp4" | "mov" | "avi" | "mkv" => {
+                    self.notifications
+                        .warning(format!("video loading not wired up yet: {}", path.display()));
+                }
+                other => {
+                    self.notifications.warning(format!("unsupported file type: .{other}"));
+                }
+            }
+        }
+    }
+
+    /// F12 saves the annotated target image to a session folder without
+    /// popping a file dialog, so an operator can grab evidence frames
+    /// without leaving the firing position.
+    fn handle_screenshot_hotkey(&mut self, ctx: &egui::Context) {
+        let pressed = ctx.input(|i| i.key_pressed(egui::Key::F12));
+        if !pressed {
+            return;
+        }
+        let Some(frame) = &self.current_frame else {
+            self.notifications.error("screenshot: no frame loaded");
+            return;
+        };
+        let annotated = export::image::render_annotated(
+            frame,
+            self.center_px,
+            self.pixels_per_mm,
+            self.overlay_rotation_deg,
+            &self.session,
+            &self.target,
+            &self.settings.overlay,
+        );
+        match export::screenshot::save_screenshot(
+            &annotated,
+            &export::screenshot::default_base_dir(),
+            &self.session,
+        ) {
+            Ok(path) => self.notifications.info(format!("Saved screenshot: {}", path.display())),
+            Err(e) => self.notifications.error(format!("screenshot: {e}")),
+        }
+    }
+
+    fn open_image_tab(&mut self) {
+        let Some(path) = rfd::FileDialog::new()
+            .add_filter("Images", &["png", "jpg", "jpeg"])
+            .pick_file()
+        else {
+            return;
+        };
+        match image::open(&path) {
+            Ok(img) => {
+                let title = path
+                    .file_name()
+                    .map(|n| n.to_string_lossy().to_string())
+                    .unwrap_or_else(|| "image".to_string());
+                self.static_tabs.open(title, img.to_rgb8());
+                self.last_opened_image_path = Some(path.clone());
+            }
+            Err(e) => self.notifications.error(format!("open image: {e}")),
+        }
+    }
+
+    fn export_annotated_image(&mut self) {
+        let Some(frame) = &self.current_frame else {
+            self.notifications.error("export annotated image: no frame loaded");
+            return;
+        };
+        let annotated = export::image::render_annotated(
+            frame,
+            self.center_px,
+            self.pixels_per_mm,
+            self.overlay_rotation_deg,
+            &self.session,
+            &self.target,
+            &self.settings.overlay,
+        );
+
+        let Some(path) = rfd::FileDialog::new()
+            .add_filter("PNG", &["png"])
+            .add_filter("JPEG", &["jpg", "jpeg"])
+            .set_file_name("scored-target.png")
+            .save_file()
+        else {
+            return;
+        };
+
+        if let Err(e) = export::image::save_annotated(&annotated, &path) {
+            self.notifications.error(format!("export annotated image: {e}"));
+        }
+    }
+
+    fn export_pdf_report(&mut self) {
+        let Some(path) = rfd::FileDialog::new()
+            .add_filter("PDF", &["pdf"])
+            .set_file_name("match-report.pdf")
+            .save_file()
+        else {
+            return;
+        };
+        match export::pdf::write_match_report(&self.session, &self.target, &path) {
+            Ok(()) => self.notifications.info(format!("Saved {}", path.display())),
+            Err(e) => self.notifications.error(format!("export PDF report: {e}")),
+        }
+    }
+
+    fn export_xlsx_report(&mut self) {
+        let Some(path) = rfd::FileDialog::new()
+            .add_filter("Excel workbook", &["xlsx"])
+            .set_file_name("match-report.xlsx")
+            .save_file()
+        else {
+            return;
+        };
+        match export::xlsx::write_workbook(&self.session, &self.target, &path) {
+            Ok(()) => self.notifications.info(format!("Saved {}", path.display())),
+            Err(e) => self.notifications.error(format!("export XLSX report: {e}")),
+        }
+    }
+
+    /// Bundles recent logs, the active processor/target config and the
+    /// last few processed frames with their detections into a zip, for
+    /// handing to remote support instead of a screen-share.
+    fn export_diagnostics(&mut self) {
+        let Some(path) = rfd::FileDialog::new()
+            .add_filter("Zip archive", &["zip"])
+            .set_file_name("precision-scorer-diagnostics.zip")
+            .save_file()
+        else {
+            return;
+        };
+        let logs = crate::telemetry::recent_logs();
+        let result = export::diagnostics::export_bundle(
+            &path,
+            &logs,
+            &self.processor.settings,
+            &self.target,
+            self.recent_diagnostic_frames.make_contiguous(),
+        );
+        match result {
+            Ok(()) => self.notifications.info(format!("Saved {}", path.display())),
+            Err(e) => self.notifications.error(format!("export diagnostics: {e}")),
+        }
+    }
+
+    /// Bundles source, crop, calibration, processor settings, target and
+    /// the full shot history into a single [`crate::project::ProjectFile`]
+    /// snapshot, for either an explicit "Save project" or a periodic
+    /// crash-recovery autosave.
+    fn snapshot_project(&self) -> crate::project::ProjectFile {
+        let source = match &self.last_opened_image_path {
+            Some(image_path) => crate::project::ProjectSource::StaticImage(image_path.clone()),
+            None => crate::project::ProjectSource::Camera,
+        };
+        crate::project::ProjectFile {
+            source,
+            crop: self.image_view.crop,
+            calibration: self.scoring_config(),
+            processor_settings: self.processor.settings.clone(),
+            target: self.target.clone(),
+            session: self.session.clone(),
+        }
+    }
+
+    /// Writes a [`Self::snapshot_project`] to a user-chosen file, so this
+    /// scoring job can be reopened exactly as left.
+    fn save_project(&mut self) {
+        let Some(path) = rfd::FileDialog::new()
+            .add_filter("Precision-scorer project", &["json"])
+            .set_file_name("project.json")
+            .save_file()
+        else {
+            return;
+        };
+        let project = self.snapshot_project();
+        match project.save_json(&path) {
+            Ok(()) => self.notifications.info(format!("Saved {}", path.display())),
+            Err(e) => self.notifications.error(format!("save project: {e}")),
+        }
+    }
+
+    fn open_project(&mut self) {
+        let Some(path) = rfd::FileDialog::new()
+            .add_filter("Precision-scorer project", &["json"])
+            .pick_file()
+        else {
+            return;
+        };
+        let project = match crate::project::ProjectFile::load_json(&path) {
+            Ok(project) => project,
+            Err(e) => {
+                self.notifications.error(format!("open project: {e}"));
+                return;
+            }
+        };
+        self.apply_project(project);
+        self.notifications.info(format!("Opened {}", path.display()));
+    }
+
+    /// Restores app state (source, crop, calibration, processor settings,
+    /// target, session) from a loaded project snapshot; shared by "Open
+    /// project" and crash-recovery restore.
+    fn apply_project(&mut self, project: crate::project::ProjectFile) {
+        match &project.source {
+            crate::project::ProjectSource::StaticImage(image_path) => match image::open(image_path) {
+                Ok(img) => {
+                    let title = image_path
+                        .file_name()
+                        .map(|n| n.to_string_lossy().to_string())
+                        .unwrap_or_else(|| "image".to_string());
+                    self.static_tabs.open(title, img.to_rgb8());
+                    self.last_opened_image_path = Some(image_path.clone());
+                }
+                Err(e) => self.notifications.error(format!("reopen project image {}: {e}", image_path.display())),
+            },
+            crate::project::ProjectSource::Camera => {
+                self.last_opened_image_path = None;
+                self.notifications
+                    .info("project used a live camera source; reconnect the camera manually".to_string());
+            }
+        }
+        self.image_view.crop = project.crop;
+        self.center_px = project.calibration.center_px;
+        self.pixels_per_mm = project.calibration.pixels_per_mm;
+        self.overlay_rotation_deg = project.calibration.rotation_deg;
+        self.processor.settings = project.processor_settings;
+        self.target = project.target;
+        self.apply_session_change("Open project", project.session);
+    }
+
+    /// Copies the currently-live calibration/processor/target/session
+    /// into `self.lanes`' active slot, so switching lanes doesn't lose
+    /// the outgoing lane's state.
+    fn save_active_lane(&mut self) {
+        let calibration = self.scoring_config();
+        if let Some(lane) = self.lanes.lanes.get_mut(self.lanes.active) {
+            lane.calibration = calibration;
+            lane.processor_settings = self.processor.settings.clone();
+            lane.target = self.target.clone();
+            lane.session = self.session.clone();
+        }
+    }
+
+    /// Loads `self.lanes`' active slot into the live calibration/
+    /// processor/target/session fields.
+    fn load_active_lane(&mut self) {
+        let Some(lane) = self.lanes.lanes.get(self.lanes.active) else { return };
+        self.center_px = lane.calibration.center_px;
+        self.pixels_per_mm = lane.calibration.pixels_per_mm;
+        self.overlay_rotation_deg = lane.calibration.rotation_deg;
+        self.processor.settings = lane.processor_settings.clone();
+        self.target = lane.target.clone();
+        let session = lane.session.clone();
+        self.apply_session_change("Switch lane", session);
+    }
+
+    /// Loads a SIUS/Meyton/Megalink export and appends it to the current
+    /// session as a new series, so mixed-source history stays in one
+    /// place. The format is guessed from the file's first line rather
+    /// than the extension, since all three interop formats use `.txt`.
+    fn import_etarget_file(&mut self) {
+        let Some(path) = rfd::FileDialog::new()
+            .add_filter("e-target export", &["txt", "csv"])
+            .pick_file()
+        else {
+            return;
+        };
+        let first_line = std::fs::read_to_string(&path)
+            .unwrap_or_default()
+            .lines()
+            .next()
+            .unwrap_or_default()
+            .to_string();
+        let imported = if first_line.starts_with("SIUS-ASCII") {
+            crate::import::read_sius_ascii(&path)
+        } else if first_line.starts_with("MEYTON EXCHANGE") {
+            crate::import::read_meyton(&path)
+        } else {
+            crate::import::read_megalink_csv(&path)
+        };
+        match imported {
+            Ok(imported) => {
+                let mut merged = self.session.clone();
+                merged.series.extend(imported.series);
+                self.apply_session_change("Import e-target session", merged);
+                self.notifications.info(format!("Imported {}", path.display()));
+            }
+            Err(e) => self.notifications.error(format!("import e-target file: {e}")),
+        }
+    }
+
+    /// Shared save-dialog plumbing for the e-target interop exporters,
+    /// which all take `(&Session, &TargetType, &Path) -> io::Result<()>`.
+    fn export_interop(
+        &mut self,
+        write: fn(&Session, &TargetType, &std::path::Path) -> std::io::Result<()>,
+        default_name: &str,
+    ) {
+        let Some(path) = rfd::FileDialog::new()
+            .add_filter("Text", &["txt"])
+            .set_file_name(default_name)
+            .save_file()
+        else {
+            return;
+        };
+        if let Err(e) = write(&self.session, &self.target, &path) {
+            self.notifications.error(format!("export: {e}"));
+        } else {
+            self.notifications.info(format!("Saved {}", path.display()));
+        }
+    }
+
+    /// Snapshots the current session as the "before" state, then applies
+    /// `replacement` and records the whole thing as one undoable step.
+    fn apply_session_change(&mut self, label: &str, replacement: Session) {
+        self.undo_stack.do_command(
+            &mut self.session,
+            Box::new(ReplaceSession { label: label.to_string(), snapshot: replacement }),
+        );
+    }
+
+    /// Applies a control action received over the API server's
+    /// `/control/*` routes, e.g. from a range-officer tablet.
+    fn apply_api_command(&mut self, command: crate::api::ApiCommand) {
+        use crate::api::ApiCommand;
+        match command {
+            ApiCommand::Freeze => self.scoring_mode = ScoringMode::Frozen,
+            ApiCommand::Resume => self.scoring_mode = ScoringMode::Live,
+            ApiCommand::ResetScorer => {
+                if self.session.all_shots().next().is_some() {
+                    if let Some(engine) = &mut self.script_engine {
+                        engine.on_session_end(&self.session);
+                    }
+                }
+                let fresh = Session::new(self.session.shooter.clone());
+                self.apply_session_change("Reset score (remote)", fresh);
+            }
+            ApiCommand::SwitchTargetPreset(name) => match crate::target::by_name(&name) {
+                Some(target) => self.target = target,
+                None => self.notifications.error(format!("unknown target preset: {name}")),
+            },
+            ApiCommand::StartNextSeries => {
+                if let Some(engine) = &mut self.script_engine {
+                    if let Some(closed) = self.session.series.last() {
+                        engine.on_series_complete(closed);
+                    }
+                }
+                let mut updated = self.session.clone();
+                updated.series.push(Series {
+                    label: format!("Series {}", updated.series.len() + 1),
+                    shots: Vec::new(),
+                });
+                self.apply_session_change("Start next series (remote)", updated);
+            }
+            ApiCommand::ChangeShooter(shooter) => self.session.shooter = shooter,
+        }
+    }
+
+    /// Applies a hot-reloaded `LaneConfig`: processor thresholds and the
+    /// target preset take effect immediately. Camera/output fields are
+    /// only read at this point — no code path yet restarts the camera or
+    /// reconnects output servers on a config change, so those are just
+    /// surfaced in a notification for now.
+    fn apply_lane_config(&mut self, config: crate::lane_config::LaneConfig) {
+        if let Some(threshold) = config.processor.threshold {
+            self.processor.settings.threshold = threshold;
+        }
+        if let Some(min_area) = config.processor.min_contour_area {
+            self.processor.settings.min_contour_area = min_area;
+        }
+        if let Some(max_area) = config.processor.max_contour_area {
+            self.processor.settings.max_contour_area = max_area;
+        }
+        if let Some(name) = &config.target_preset {
+            match crate::target::by_name(name) {
+                Some(target) => self.target = target,
+                None => self.notifications.error(format!("unknown target preset in lane config: {name}")),
+            }
+        }
+        if config.camera.resolution.is_some() || config.outputs.mqtt_broker.is_some()
+            || config.outputs.api_addr.is_some()
+        {
+            self.notifications.info(
+                "lane config reloaded (camera/output changes require reconnecting manually)".to_string(),
+            );
+        } else {
+            self.notifications.info("lane config reloaded".to_string());
+        }
+    }
+}
+
+impl MyApp {
+    /// Whether the match timer (if running) currently forbids new shots.
+    pub fn shots_locked(&self) -> bool {
+        self.match_timer.as_ref().is_some_and(MatchTimer::shots_locked)
+    }
+
+    /// Offers to restore an autosave left behind by an unclean exit.
+    fn show_recovery_prompt(&mut self, ctx: &egui::Context) {
+        if self.pending_recovery.is_none() {
+            return;
+        }
+        let mut restore = false;
+        let mut discard = false;
+        egui::Window::new("Restore previous session?")
+            .collapsible(false)
+            .resizable(false)
+            .show(ctx, |ui| {
+                ui.label("A previous session wasn't shut down cleanly. Restore it?");
+                ui.horizontal(|ui| {
+                    if ui.button("Restore").clicked() {
+                        restore = true;
+                    }
+                    if ui.button("Discard").clicked() {
+                        discard = true;
+                    }
+                });
+            });
+        if restore {
+            if let Some(project) = self.pending_recovery.take() {
+                self.apply_project(project);
+                self.notifications.info("Restored autosaved session".to_string());
+            }
+            crate::recovery::clear();
+        } else if discard {
+            self.pending_recovery = None;
+            crate::recovery::clear();
+        }
+    }
+}
+
+impl eframe::App for MyApp {
+    fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
+        self.settings.theme.apply(ctx);
+        ctx.style_mut(|style| self.touch_mode.apply(style));
+
+        puffin::GlobalProfiler::lock().new_frame();
+        if self.show_profiler_window {
+            puffin_egui::profiler_window(ctx);
+        }
+
+        self.show_recovery_prompt(ctx);
+
+        if let Some(server) = &self.api_server {
+            server.publish(&self.session);
+            while let Some(command) = server.poll_command() {
+                self.apply_api_command(command);
+            }
+        }
+        if let Some(overlay) = &self.stream_overlay {
+            overlay.publish(&self.session, &self.target);
+        }
+        if let (Some(server), Some(frame)) = (&self.mjpeg_server, &self.current_frame) {
+            let annotated = export::image::render_annotated(
+                frame,
+                self.center_px,
+                self.pixels_per_mm,
+                self.overlay_rotation_deg,
+                &self.session,
+                &self.target,
+                &self.settings.overlay,
+            );
+            server.publish_frame(&annotated);
+        }
+        if let (Some(sender), Some(frame)) = (&self.remote_frame_sender, &self.current_frame) {
+            sender.publish_frame(frame);
+        }
+        if let Some(receiver) = &self.remote_frame_receiver {
+            if let Some(frame) = receiver.poll_frame() {
+                self.current_frame = Some(frame);
+            }
+        }
+        if let Some(watcher) = &mut self.lane_config_watcher {
+            if let Some(result) = watcher.poll() {
+                match result {
+                    Ok(config) => self.apply_lane_config(config),
+                    Err(e) => self.notifications.error(format!("lane config reload: {e}")),
+                }
+            }
+        }
+        if self.autosave_timer.poll() {
+            let project = self.snapshot_project();
+            if let Err(e) = crate::recovery::save(&project) {
+                tracing::warn!(error = %e, "autosave failed");
+            }
+        }
+        if self.replay_playing {
+            match self.replay_player.as_mut().and_then(Iterator::next) {
+                Some(crate::replay::ReplayEntry::Frame { jpeg }) => match crate::replay::Player::decode_frame(&jpeg) {
+                    Ok(frame) => self.current_frame = Some(frame),
+                    Err(e) => self.notifications.error(format!("replay: {e}")),
+                },
+                // Reapply recorded parameter changes onto the live state
+                // before the frame they preceded is re-scored below, so a
+                // session where calibration or detector settings changed
+                // mid-recording replays against what was live at the time
+                // instead of whatever happens to be live now.
+                Some(crate::replay::ReplayEntry::Event(crate::events::AppEvent::CalibrationChanged(config))) => {
+                    self.center_px = config.center_px;
+                    self.pixels_per_mm = config.pixels_per_mm;
+                    self.overlay_rotation_deg = config.rotation_deg;
+                }
+                Some(crate::replay::ReplayEntry::Event(crate::events::AppEvent::ProcessorSettingsChanged(settings))) => {
+                    self.processor.settings = settings;
+                }
+                Some(crate::replay::ReplayEntry::Event(_)) => {}
+                None => {
+                    self.replay_playing = false;
+                    self.notifications.info("Replay finished".to_string());
+                }
+            }
+        }
+        let calibration = self.scoring_config();
+        if self.last_published_calibration != Some(calibration) {
+            self.event_bus.publish(crate::events::AppEvent::CalibrationChanged(calibration));
+            self.last_published_calibration = Some(calibration);
+        }
+        if self.last_published_processor_settings.as_ref() != Some(&self.processor.settings) {
+            self.event_bus
+                .publish(crate::events::AppEvent::ProcessorSettingsChanged(self.processor.settings.clone()));
+            self.last_published_processor_settings = Some(self.processor.settings.clone());
+        }
+        *self.pipeline_config.settings.lock().unwrap() = self.processor.settings.clone();
+        *self.pipeline_config.calibration.lock().unwrap() = calibration;
+        *self.pipeline_config.target.lock().unwrap() = self.target.clone();
+        if let Some(frame) = self.current_frame.clone() {
+            if let Some(recorder) = &mut self.recorder {
+                recorder.record_frame(&frame);
+            }
+            self.frame_pipeline.push_frame(frame);
+        }
+        if let Some(rx) = &self.recorder_event_rx {
+            let events: Vec<_> = rx.try_iter().collect();
+            if let Some(recorder) = &mut self.recorder {
+                for event in events {
+                    recorder.record_event(&event);
+                }
+            }
+        }
+        if let Some(rendered) = self.frame_pipeline.try_take_rendered() {
+            self.processor.last_metrics = rendered.metrics;
+            self.processor.last_error = rendered.error;
+            self.event_bus
+                .publish(crate::events::AppEvent::DetectionUpdated(rendered.detections.clone()));
+            if self.recent_diagnostic_frames.len() == DIAGNOSTIC_FRAME_HISTORY {
+                self.recent_diagnostic_frames.pop_front();
+            }
+            self.recent_diagnostic_frames.push_back(crate::export::diagnostics::DiagnosticFrame {
+                frame: rendered.image,
+                detections: rendered.detections,
+            });
+        }
+        {
+            let shots: Vec<_> = self.session.all_shots().cloned().collect();
+            for shot in shots.iter().skip(self.last_event_shot_count) {
+                self.event_bus.publish(crate::events::AppEvent::ShotScored(shot.clone()));
+            }
+            self.last_event_shot_count = shots.len();
+        }
+        if let Some(broadcaster) = &self.shot_broadcaster {
+            let shots: Vec<_> = self.session.all_shots().cloned().collect();
+            for shot in shots.iter().skip(self.last_broadcast_shot_count) {
+                broadcaster.broadcast_shot(shot);
+            }
+            self.last_broadcast_shot_count = shots.len();
+        }
+        if let Some(publisher) = &self.mqtt_publisher {
+            let shots: Vec<_> = self.session.all_shots().cloned().collect();
+            let has_new = shots.len() > self.last_mqtt_shot_count;
+            for shot in shots.iter().skip(self.last_mqtt_shot_count) {
+                publisher.publish_shot(shot);
+            }
+            if has_new {
+                publisher.publish_session_summary(&self.session);
+            }
+            self.last_mqtt_shot_count = shots.len();
+        }
+        if let Some(broadcaster) = &self.udp_broadcaster {
+            let shots: Vec<_> = self.session.all_shots().cloned().collect();
+            for shot in shots.iter().skip(self.last_udp_shot_count) {
+                broadcaster.broadcast_shot(shot);
+            }
+            self.last_udp_shot_count = shots.len();
+        }
+        if let Some(feed) = &self.csv_feed {
+            let shots: Vec<_> = self.session.all_shots().cloned().collect();
+            for shot in shots.iter().skip(self.last_csv_shot_count) {
+                if let Err(e) = feed.append_shot(shot) {
+                    self.notifications.error(format!("CSV feed: {e}"));
+                }
+            }
+            self.last_csv_shot_count = shots.len();
+        }
+        if self.settings.shot_trigger.enabled {
+            let shots: Vec<_> = self.session.all_shots().cloned().collect();
+            for shot in shots.iter().skip(self.last_shot_trigger_shot_count).cloned() {
+                let config = self.settings.shot_trigger.clone();
+                std::thread::spawn(move || {
+                    if let Err(e) = crate::shot_trigger::fire(&config, &shot) {
+                        tracing::warn!(error = %e, "shot trigger failed");
+                    }
+                });
+            }
+            self.last_shot_trigger_shot_count = shots.len();
+        }
+        if let Some(scoreboard) = &mut self.led_scoreboard {
+            let shots: Vec<_> = self.session.all_shots().cloned().collect();
+            if shots.len() != self.last_led_shot_count {
+                let last_value = shots.last().map(|s| s.value);
+                if let Err(e) = scoreboard.update(self.session.total(), last_value) {
+                    self.notifications.error(format!("LED scoreboard: {e}"));
+                }
+                self.last_led_shot_count = shots.len();
+            }
+        }
+        if let Some(rx) = &self.script_event_rx {
+            for event in rx.try_iter().collect::<Vec<_>>() {
+                if let crate::events::AppEvent::ShotScored(shot) = event {
+                    if let Some(engine) = &mut self.script_engine {
+                        engine.on_shot(&shot);
+                    }
+                }
+            }
+        }
+        if let Some(engine) = &mut self.script_engine {
+            for message in engine.take_messages() {
+                self.notifications.info(message);
+            }
+        }
+        self.match_timer_splits();
+        self.fuse_acoustic_input();
+
+        ctx.input(|i| {
+            if i.modifiers.command && i.key_pressed(egui::Key::Z) {
+                if !self.undo_stack.undo(&mut self.session) {
+                    self.notifications.info("nothing to undo");
+                }
+            } else if i.modifiers.command && i.key_pressed(egui::Key::Y) {
+                if !self.undo_stack.redo(&mut self.session) {
+                    self.notifications.info("nothing to redo");
+                }
+            }
+        });
+
+        if let Some(timer) = &mut self.match_timer {
+            if let Some(warning) = timer.tick() {
+                self.notifications.warning(warning.to_string());
+            }
+            ctx.request_repaint_after(std::time::Duration::from_millis(200));
+        }
+
+        egui::TopBottomPanel::top("top_panel").show(ctx, |ui| {
+            ui.horizontal(|ui| {
+                ui.heading("precision-scorer");
+                ui.separator();
+                if let Some(timer) = &self.match_timer {
+                    let remaining = timer.remaining();
+                    let phase = match timer.phase() {
+                        MatchPhase::Preparation => "Prep",
+                        MatchPhase::Match => "Match",
+                        MatchPhase::Expired => "Expired",
+                    };
+                    ui.label(
+                        egui::RichText::new(format!(
+                            "{phase}  {:02}:{:02}",
+                            remaining.as_secs() / 60,
+                            remaining.as_secs() % 60
+                        ))
+                        .strong()
+                        .size(20.0),
+                    );
+                } else if ui.button("Start match timer").clicked() {
+                    self.match_timer = Some(MatchTimer::new(self.timer_config));
+                }
+                ui.separator();
+                if ui.button("Save project…").clicked() {
+                    self.save_project();
+                }
+                if ui.button("Open project…").clicked() {
+                    self.open_project();
+                }
+                ui.separator();
+                if ui.checkbox(&mut self.show_profiler_window, "Profiler").changed() {
+                    puffin::set_scopes_on(self.show_profiler_window);
+                }
+                ui.separator();
+                if ui.button("Print scorecard").clicked() {
+                    if let Err(e) = export::scorecard::print_scorecard(&self.session, &self.target)
+                    {
+                        self.notifications.error(format!("print scorecard: {e}"));
+                    }
+                }
+                if ui.button("Export annotated image…").clicked() {
+                    self.export_annotated_image();
+                }
+                if ui.button("Export SIUS…").clicked() {
+                    self.export_interop(export::interop::write_sius_ascii, "sius.txt");
+                }
+                if ui.button("Export Meyton…").clicked() {
+                    self.export_interop(export::interop::write_meyton, "meyton.txt");
+                }
+                if ui.button("Export PDF report…").clicked() {
+                    self.export_pdf_report();
+                }
+                if ui.button("Export XLSX report…").clicked() {
+                    self.export_xlsx_report();
+                }
+                if ui.button("Export diagnostics…").clicked() {
+                    self.export_diagnostics();
+                }
+                if ui.button("Export league CSV…").clicked() {
+                    self.show_league_csv_settings = true;
+                }
+                if ui.button("Import e-target file…").clicked() {
+                    self.import_etarget_file();
+                }
+                if ui.button("Compare sessions…").clicked() {
+                    self.show_compare_window = true;
+                }
+                if ui.button("Replay…").clicked() {
+                    self.show_replay_window = true;
+                }
+                ui.checkbox(&mut self.touch_mode.enabled, "Touch mode");
+                if ui.button("Calibration wizard…").clicked() {
+                    self.calibration_wizard =
+                        Some(crate::ui::calibration_wizard::CalibrationWizard::default());
+                }
+                if ui.button("Open image…").clicked() {
+                    self.open_image_tab();
+                }
+                if ui.button("Overlay colors…").clicked() {
+                    self.show_overlay_settings = true;
+                }
+                if ui.button("Theme…").clicked() {
+                    self.show_theme_settings = true;
+                }
+                if ui.button("Notifications…").clicked() {
+                    self.show_notification_history = true;
+                }
+                if ui.button("MQTT…").clicked() {
+                    self.show_mqtt_settings = true;
+                }
+                if ui.button("Cloud sync…").clicked() {
+                    self.show_sync_settings = true;
+                }
+                if ui.button("Milestone webhook…").clicked() {
+                    self.show_webhook_settings = true;
+                }
+                if ui.button("LED scoreboard…").clicked() {
+                    self.show_led_scoreboard_settings = true;
+                }
+                if ui.button("Shot trigger…").clicked() {
+                    self.show_shot_trigger_settings = true;
+                }
+                if ui.button("Units…").clicked() {
+                    self.show_units_settings = true;
+                }
+                if ui.button("History…").clicked() {
+                    self.show_history_window = true;
+                }
+                if self.settings.sync.backend != crate::sync::SyncBackend::Disabled
+                    && ui.button("Sync now").clicked()
+                {
+                    self.sync_current_session();
+                }
+                ui.separator();
+                if let Some(overlay) = &self.stream_overlay {
+                    ui.label(format!("OBS overlay: http://{}/overlay", overlay.addr()));
+                } else {
+                    ui.text_edit_singleline(&mut self.stream_overlay_addr);
+                    if ui.button("Start OBS overlay").clicked() {
+                        match crate::stream_overlay::StreamOverlayServer::start(
+                            &self.stream_overlay_addr,
+                        ) {
+                            Ok(overlay) => {
+                                self.notifications.info(format!(
+                                    "OBS overlay page: http://{}/overlay",
+                                    overlay.addr()
+                                ));
+                                self.stream_overlay = Some(overlay);
+                            }
+                            Err(e) => self.notifications.error(format!("start OBS overlay: {e}")),
+                        }
+                    }
+                }
+                if ui.button("Companion (QR)…").clicked() {
+                    self.show_companion_window = true;
+                }
+                if let Some(server) = &self.mjpeg_server {
+                    ui.label(format!("MJPEG: http://{}/stream", server.addr()));
+                } else {
+                    ui.text_edit_singleline(&mut self.mjpeg_addr);
+                    if ui.button("Start MJPEG stream").clicked() {
+                        match crate::mjpeg::MjpegServer::start(&self.mjpeg_addr) {
+                            Ok(server) => {
+                                self.notifications
+                                    .info(format!("MJPEG stream: http://{}/stream", server.addr()));
+                                self.mjpeg_server = Some(server);
+                            }
+                            Err(e) => self.notifications.error(format!("start MJPEG stream: {e}")),
+                        }
+                    }
+                }
+                if let Some(sender) = &self.remote_frame_sender {
+                    ui.label(format!("Target-line sender: {}", sender.addr()));
+                } else {
+                    ui.text_edit_singleline(&mut self.remote_frame_sender_addr);
+                    if ui.button("Start target-line sender").clicked() {
+                        match crate::remote_camera::FrameSender::start(&self.remote_frame_sender_addr) {
+                            Ok(sender) => {
+                                self.notifications
+                                    .info(format!("target-line sender listening on {}", sender.addr()));
+                                self.remote_frame_sender = Some(sender);
+                            }
+                            Err(e) => self.notifications.error(format!("start target-line sender: {e}")),
+                        }
+                    }
+                }
+                if let Some(receiver) = &self.remote_frame_receiver {
+                    ui.label(format!("Firing-line receiver: {}", receiver.addr()));
+                } else {
+                    ui.text_edit_singleline(&mut self.remote_frame_receiver_addr);
+                    if ui.button("Connect firing-line receiver").clicked() {
+                        match crate::remote_camera::FrameReceiver::connect(&self.remote_frame_receiver_addr)
+                        {
+                            Ok(receiver) => {
+                                self.notifications
+                                    .info(format!("receiving frames from {}", receiver.addr()));
+                                self.remote_frame_receiver = Some(receiver);
+                            }
+                            Err(e) => self.notifications.error(format!("connect frame receiver: {e}")),
+                        }
+                    }
+                }
+                if let Some(watcher) = &self.lane_config_watcher {
+                    ui.label(format!("Lane config: {} (hot-reloaded)", watcher.path().display()));
+                } else {
+                    ui.horizontal(|ui| {
+                        ui.text_edit_singleline(&mut self.lane_config_path);
+                        if ui.button("Browse…").clicked() {
+                            if let Some(path) = rfd::FileDialog::new().add_filter("TOML", &["toml"]).pick_file() {
+                                self.lane_config_path = path.display().to_string();
+                            }
+                        }
+                    });
+                    if ui.button("Load lane config").clicked() {
+                        self.lane_config_watcher =
+                            Some(crate::lane_config::HotReloadWatcher::new(self.lane_config_path.clone().into()));
+                    }
+                }
+                if self.script_engine.is_some() {
+                    ui.label(format!("Script: {}", self.script_path));
+                    if ui.button("Unload script").clicked() {
+                        self.script_engine = None;
+                        self.script_event_rx = None;
+                    }
+                } else {
+                    ui.horizontal(|ui| {
+                        ui.text_edit_singleline(&mut self.script_path);
+                        if ui.button("Browse…").clicked() {
+                            if let Some(path) = rfd::FileDialog::new().add_filter("Rhai script", &["rhai"]).pick_file() {
+                                self.script_path = path.display().to_string();
+                            }
+                        }
+                    });
+                    if ui.button("Load script").clicked() {
+                        match crate::scripting::ScriptEngine::load(std::path::Path::new(&self.script_path)) {
+                            Ok(engine) => {
+                                self.script_engine = Some(engine);
+                                self.script_event_rx = Some(self.event_bus.subscribe());
+                                self.notifications.info(format!("Loaded script {}", self.script_path));
+                            }
+                            Err(e) => self.notifications.error(format!("load script: {e}")),
+                        }
+                    }
+                }
+                if self.recorder.is_some() {
+                    if ui.button("Stop recording").clicked() {
+                        self.recorder = None;
+                        self.recorder_event_rx = None;
+                    }
+                } else {
+                    ui.horizontal(|ui| {
+                        ui.text_edit_singleline(&mut self.recording_path);
+                        if ui.button("Browse…").clicked() {
+                            if let Some(path) = rfd::FileDialog::new().add_filter("Replay log", &["jsonl"]).save_file()
+                            {
+                                self.recording_path = path.display().to_string();
+                            }
+                        }
+                    });
+                    if ui.button("Start recording").clicked() {
+                        match crate::replay::Recorder::start(std::path::Path::new(&self.recording_path)) {
+                            Ok(recorder) => {
+                                self.recorder = Some(recorder);
+                                self.recorder_event_rx = Some(self.event_bus.subscribe());
+                                self.notifications.info(format!("Recording to {}", self.recording_path));
+                            }
+                            Err(e) => self.notifications.error(format!("start recording: {e}")),
+                        }
+                    }
+                }
+                if self.replay_player.is_some() {
+                    ui.label(format!("Replay: {}", self.replay_path));
+                    ui.checkbox(&mut self.replay_playing, "Playing");
+                    if ui.button("Stop replay").clicked() {
+                        self.replay_player = None;
+                        self.replay_playing = false;
+                    }
+                } else {
+                    ui.horizontal(|ui| {
+                        ui.text_edit_singleline(&mut self.replay_path);
+                        if ui.button("Browse…").clicked() {
+                            if let Some(path) = rfd::FileDialog::new().add_filter("Replay log", &["jsonl"]).pick_file()
+                            {
+                                self.replay_path = path.display().to_string();
+                            }
+                        }
+                    });
+                    if ui.button("Load replay").clicked() {
+                        match crate::replay::Player::load(std::path::Path::new(&self.replay_path)) {
+                            Ok(player) => {
+                                self.replay_player = Some(player);
+                                self.replay_playing = true;
+                                self.notifications.info(format!("Loaded replay {}", self.replay_path));
+                            }
+                            Err(e) => self.notifications.error(format!("load replay: {e}")),
+                        }
+                    }
+                }
+                if let Some(client) = &self.shot_timer {
+                    ui.label(format!(
+                        "Shot timer: {} ({} pending)",
+                        client.port_name(),
+                        self.pending_timer_splits.len()
+                    ));
+                } else {
+                    ui.text_edit_singleline(&mut self.shot_timer_port);
+                    if ui.button("Connect shot timer").clicked() {
+                        match crate::shot_timer::ShotTimerClient::connect(&self.shot_timer_port, 9600) {
+                            Ok(client) => {
+                                self.notifications
+                                    .info(format!("Shot timer connected on {}", client.port_name()));
+                                self.last_timer_matched_shot_count = self.session.all_shots().count();
+                                self.shot_timer = Some(client);
+                            }
+                            Err(e) => self.notifications.error(format!("connect shot timer: {e}")),
+                        }
+                    }
+                }
+                if let Some(client) = &self.acoustic_client {
+                    ui.label(format!("Acoustic input: {}", client.source()));
+                } else {
+                    ui.text_edit_singleline(&mut self.acoustic_addr);
+                    if ui.button("Connect acoustic input (TCP)").clicked() {
+                        match crate::acoustic::AcousticInputClient::connect_tcp(&self.acoustic_addr) {
+                            Ok(client) => {
+                                self.notifications
+                                    .info(format!("Acoustic input connected on {}", client.source()));
+                                self.last_acoustic_matched_shot_count = self.session.all_shots().count();
+                                self.acoustic_client = Some(client);
+                            }
+                            Err(e) => self.notifications.error(format!("connect acoustic input: {e}")),
+                        }
+                    }
+                }
+                ui.separator();
+                if let Some(server) = &self.api_server {
+                    ui.label(format!("API: http://{}", server.addr()));
+                } else {
+                    ui.text_edit_singleline(&mut self.api_addr);
+                    ui.label("Control token (blank disables /control/*):");
+                    ui.add(egui::TextEdit::singleline(&mut self.api_token).password(true));
+                    if ui.button("Start API server").clicked() {
+                        match crate::api::ApiServer::start(
+                            &self.api_addr,
+                            self.session.clone(),
+                            self.api_token.clone(),
+                        ) {
+                            Ok(server) => {
+                                self.notifications
+                                    .info(format!("API server listening on {}", server.addr()));
+                                self.api_server = Some(server);
+                            }
+                            Err(e) => self.notifications.error(format!("start API server: {e}")),
+                        }
+                    }
+                }
+                if let Some(broadcaster) = &self.shot_broadcaster {
+                    ui.label(format!("WS: ws://{}", broadcaster.addr()));
+                } else {
+                    ui.text_edit_singleline(&mut self.ws_addr);
+                    if ui.button("Start shot stream").clicked() {
+                        match crate::ws::ShotBroadcaster::start(&self.ws_addr) {
+                            Ok(broadcaster) => {
+                                self.notifications
+                                    .info(format!("Shot stream listening on {}", broadcaster.addr()));
+                                self.last_broadcast_shot_count = self.session.all_shots().count();
+                                self.shot_broadcaster = Some(broadcaster);
+                            }
+                            Err(e) => self.notifications.error(format!("start shot stream: {e}")),
+                        }
+                    }
+                }
+                if let Some(broadcaster) = &self.udp_broadcaster {
+                    ui.label(format!(
+                        "UDP: lane {} -> {}",
+                        broadcaster.lane(),
+                        broadcaster.broadcast_addr()
+                    ));
+                } else {
+                    ui.label("Lane:");
+                    ui.add(egui::DragValue::new(&mut self.udp_lane).range(1..=999));
+                    ui.text_edit_singleline(&mut self.udp_broadcast_addr);
+                    if ui.button("Start UDP broadcast").clicked() {
+                        match crate::udp::UdpBroadcaster::start(&self.udp_broadcast_addr, self.udp_lane) {
+                            Ok(broadcaster) => {
+                                self.notifications.info(format!(
+                                    "Broadcasting lane {} shots to {}",
+                                    broadcaster.lane(),
+                                    broadcaster.broadcast_addr()
+                                ));
+                                self.last_udp_shot_count = self.session.all_shots().count();
+                                self.udp_broadcaster = Some(broadcaster);
+                            }
+                            Err(e) => self.notifications.error(format!("start UDP broadcast: {e}")),
+                        }
+                    }
+                }
+                if let Some(feed) = &self.csv_feed {
+                    ui.label(format!("CSV feed: {}", feed.path().display()));
+                } else if ui.button("Start CSV feed…").clicked() {
+                    if let Some(path) = rfd::FileDialog::new().add_filter("CSV", &["csv"]).save_file() {
+                        match crate::csv_feed::CsvFeed::start(path) {
+                            Ok(feed) => {
+                                self.notifications
+                                    .info(format!("Appending shots to {}", feed.path().display()));
+                                self.last_csv_shot_count = self.session.all_shots().count();
+                                self.csv_feed = Some(feed);
+                            }
+                            Err(e) => self.notifications.error(format!("start CSV feed: {e}")),
+                        }
+                    }
+                }
+                ui.separator();
+                if ui
+                    .add_enabled(self.undo_stack.can_undo(), egui::Button::new("Undo"))
+                    .clicked()
+                {
+                    self.undo_stack.undo(&mut self.session);
+                }
+                if ui
+                    .add_enabled(self.undo_stack.can_redo(), egui::Button::new("Redo"))
+                    .clicked()
+                {
+                    self.undo_stack.redo(&mut self.session);
+                }
+                if ui.button("Reset score").clicked() {
+                    if self.session.all_shots().count() > 0 {
+                        let best_before = crate::history::list_history()
+                            .iter()
+                            .filter(|e| e.discipline == self.target.name)
+                            .map(|e| e.total)
+                            .fold(f32::MIN, f32::max);
+                        match crate::history::save_to_history(&self.session, &self.target.name) {
+                            Ok(path) => self.notifications.info(format!("Archived to {}", path.display())),
+                            Err(e) => self.notifications.error(format!("archive session: {e}")),
+                        }
+                        let total = self.session.total();
+                        let message = if total > best_before {
+                            format!(
+                                "New personal best! {} scored {:.1} ({} X) on {}",
+                                self.session.shooter.name, total, self.session.x_count(), self.target.name
+                            )
+                        } else {
+                            format!(
+                                "Series complete: {} scored {:.1} ({} X) on {}",
+                                self.session.shooter.name, total, self.session.x_count(), self.target.name
+                            )
+                        };
+                        self.post_webhook_milestone(message);
+                        if let Some(engine) = &mut self.script_engine {
+                            engine.on_session_end(&self.session);
+                        }
+                    }
+                    let fresh = Session::new(self.session.shooter.clone());
+                    self.apply_session_change("Reset score", fresh);
+                }
+            });
+        });
+
+        self.notifications.show_toasts(ctx);
+        if self.show_notification_history {
+            egui::Window::new("Notifications").open(&mut self.show_notification_history).show(
+                ctx,
+                |ui| {
+                    egui::ScrollArea::vertical().show(ui, |ui| {
+                        for note in self.notifications.history() {
+                            ui.label(&note.message);
+                        }
+                    });
+                },
+            );
+        }
+
+        egui::TopBottomPanel::bottom("status_bar").show(ctx, |ui| {
+            crate::ui::status_bar::show(
+                ui,
+                self.camera.as_ref().map(Camera::stats),
+                self.processor.last_metrics,
+                &self.scoring_config(),
+                self.processor.last_error.as_ref(),
+            );
+        });
+
+        egui::Window::new("Compare sessions")
+            .open(&mut self.show_compare_window)
+            .show(ctx, |ui| self.compare_view.show(ui));
+
+        if self.show_history_window {
+            let mut opened = None;
+            egui::Window::new("History").open(&mut self.show_history_window).show(ctx, |ui| {
+                opened = self.history_browser.show(ui);
+            });
+            if opened.is_some() {
+                self.viewed_history_session = opened;
+            }
+        }
+        if let Some(viewed) = self.viewed_history_session.clone() {
+            let mut open = true;
+            egui::Window::new(format!("History: {}", viewed.shooter.name)).open(&mut open).show(
+                ctx,
+                |ui| {
+                    ui.label(format!(
+                        "Total: {:.1}   X-count: {}   Series: {}",
+                        viewed.total(),
+                        viewed.x_count(),
+                        viewed.series.len()
+                    ));
+                    crate::ui::trend_chart::show(ui, &viewed, &self.settings.units);
+                },
+            );
+            if !open {
+                self.viewed_history_session = None;
+            }
+        }
+
+        let dt = ctx.input(|i| i.stable_dt);
+        egui::Window::new("Replay")
+            .open(&mut self.show_replay_window)
+            .show(ctx, |ui| self.replay.show(ui, &self.session, dt));
+        if self.replay.playing {
+            ctx.request_repaint();
+        }
+
+        if self.scoring_mode == ScoringMode::Live {
+            self.handle_dropped_files(ctx);
+            self.handle_screenshot_hotkey(ctx);
+        }
+
+        let overlay_changed = self.show_overlay_window(ctx);
+        let theme_changed = self.show_theme_window(ctx);
+        let mqtt_changed = self.show_mqtt_window(ctx);
+        let sync_changed = self.show_sync_window(ctx);
+        let webhook_changed = self.show_webhook_window(ctx);
+        let shot_trigger_changed = self.show_shot_trigger_window(ctx);
+        let units_changed = self.show_units_window(ctx);
+        self.show_companion_window(ctx);
+        self.show_led_scoreboard_window(ctx);
+        self.show_league_csv_window(ctx);
+        if overlay_changed
+            || theme_changed
+            || mqtt_changed
+            || sync_changed
+            || webhook_changed
+            || shot_trigger_changed
+            || units_changed
+        {
+            if let Err(e) = self.settings.save() {
+                self.notifications.error(format!("save settings: {e}"));
+            }
+        }
+
+        if let Some(wizard) = &mut self.calibration_wizard {
+            let mut open = true;
+            let mut finished = None;
+            egui::Window::new("Calibration wizard").open(&mut open).show(ctx, |ui| {
+                finished = wizard.show(ui, &self.target);
+            });
+            if let Some(config) = finished {
+                self.center_px = config.center_px;
+                self.pixels_per_mm = config.pixels_per_mm;
+                self.overlay_rotation_deg = config.rotation_deg;
+                open = false;
+            }
+            if !open {
+                self.calibration_wizard = None;
+            }
+        }
+
+        egui::SidePanel::right("trend_panel").show(ctx, |ui| {
+            ui.heading("Trend");
+            crate::ui::trend_chart::show(ui, &self.session, &self.settings.units);
+
+            ui.separator();
+            ui.heading("Threshold tuning");
+            ui.checkbox(&mut self.image_view.crop_enabled, "Crop ROI (drag handles on image)");
+            if let Some(frame) = &self.current_frame {
+                let region = self.image_view.crop_region(frame);
+                let key = (crate::ui::image_view::hash_frame(frame), region);
+                if self.cached_roi_key != Some(key) {
+                    puffin::profile_scope!("crop");
+                    let gray = image::imageops::grayscale(frame);
+                    self.cached_roi = Some(match region {
+                        Some((x, y, w, h)) => image::imageops::crop_imm(&gray, x, y, w, h).to_image(),
+                        None => gray,
+                    });
+                    self.cached_roi_key = Some(key);
+                }
+                let roi = self.cached_roi.as_ref().expect("just populated above");
+                let histogram = crate::ui::histogram::compute(roi);
+                crate::ui::histogram::show(ui, &histogram, &mut self.processor.settings.threshold);
+            } else {
+                ui.label("No frame loaded.");
+            }
+
+            ui.separator();
+            ui.heading("Detection model");
+            let settings = &mut self.processor.settings;
+            ui.radio_value(&mut settings.backend, crate::processor::DetectionBackendKind::Threshold, "Threshold");
+            ui.radio_value(&mut settings.backend, crate::processor::DetectionBackendKind::Hough, "Hough circles");
+            ui.radio_value(&mut settings.backend, crate::processor::DetectionBackendKind::Template, "Template match");
+            ui.radio_value(&mut settings.backend, crate::processor::DetectionBackendKind::Onnx, "Custom ONNX");
+            if settings.backend == crate::processor::DetectionBackendKind::Template {
+                ui.horizontal(|ui| {
+                    ui.label(settings.template.template_path.display().to_string());
+                    if ui.button("Browse…").clicked() {
+                        if let Some(path) = rfd::FileDialog::new()
+                            .add_filter("image", &["png", "jpg", "jpeg", "bmp"])
+                            .pick_file()
+                        {
+                            settings.template.template_path = path;
+                        }
+                    }
+                });
+                ui.add(
+                    egui::Slider::new(&mut settings.template.match_threshold, 0.0..=1.0)
+                        .text("Match threshold"),
+                );
+            }
+            if settings.backend == crate::processor::DetectionBackendKind::Onnx {
+                ui.horizontal(|ui| {
+                    ui.label(settings.onnx.model_path.display().to_string());
+                    if ui.button("Browse…").clicked() {
+                        if let Some(path) =
+                            rfd::FileDialog::new().add_filter("ONNX model", &["onnx"]).pick_file()
+                        {
+                            settings.onnx.model_path = path;
+                        }
+                    }
+                });
+                ui.add(egui::DragValue::new(&mut settings.onnx.input_size).range(32..=4096).prefix("Input size: "));
+                ui.add(
+                    egui::Slider::new(&mut settings.onnx.confidence_threshold, 0.0..=1.0)
+                        .text("Confidence threshold"),
+                );
+                ui.add(
+                    egui::Slider::new(&mut settings.onnx.nms_threshold, 0.0..=1.0).text("NMS threshold"),
+                );
+                let mut class_map = settings.onnx.class_map.join(",");
+                if ui.text_edit_singleline(&mut class_map).changed() {
+                    settings.onnx.class_map = class_map.split(',').map(|s| s.trim().to_string()).collect();
+                }
+                ui.label("Live-applied to the next processed frame — no restart needed.");
+            }
+        });
+
+        egui::TopBottomPanel::top("lane_strip").show(ctx, |ui| {
+            ui.horizontal(|ui| {
+                ui.checkbox(&mut self.lane_tiled_overview, "Tiled overview");
+                let switched = if self.lane_tiled_overview {
+                    let total = self.session.total();
+                    let x_count = self.session.x_count();
+                    self.lanes.show_tiled_overview(ui, total, x_count)
+                } else {
+                    self.lanes.show_switcher(ui)
+                };
+                if let Some(new_index) = switched {
+                    self.save_active_lane();
+                    self.lanes.active = new_index;
+                    self.load_active_lane();
+                }
+                if ui.button("New lane").clicked() {
+                    self.save_active_lane();
+                    let name = format!("Lane {}", self.lanes.lanes.len() + 1);
+                    self.lanes.add_lane(crate::lane::Lane::new(name, self.target.clone()));
+                    self.load_active_lane();
+                }
+            });
+        });
+
+        if !self.static_tabs.tabs.is_empty() {
+            egui::TopBottomPanel::top("tab_strip").show(ctx, |ui| {
+                self.static_tabs.show_strip(ui);
+            });
+        }
+
+        egui::CentralPanel::default().show(ctx, |ui| {
+            ui.horizontal(|ui| {
+                ui.label(format!(
+                    "Shooter: {}  Total: {:.1}  X-count: {}",
+                    self.session.shooter.name,
+                    self.session.total(),
+                    self.session.x_count()
+                ));
+                if let Some(last) = self.session.all_shots().last() {
+                    let size = 16.0 + self.image_view.last_shot_readout_boost() * 10.0;
+                    ui.label(egui::RichText::new(format!("Last: {:.1}", last.value)).size(size).strong());
+                }
+            });
+            ui.add(
+                egui::Slider::new(&mut self.overlay_rotation_deg, -180.0..=180.0)
+                    .text("Overlay rotation (°)"),
+            );
+            ui.add(
+                egui::Slider::new(&mut self.gauge_diameter_mm, 1.0..=20.0).text("Gauge diameter (mm)"),
+            );
+            if let Some(pos) = self.image_view.last_click_px.take() {
+                self.two_point_calibrate.record_click(pos);
+                self.measure_tool.record_click(pos);
+            }
+            if let Some(config) = self.two_point_calibrate.show(ui, self.scoring_config()) {
+                self.pixels_per_mm = config.pixels_per_mm;
+            }
+            self.measure_tool.show(ui, self.pixels_per_mm, &self.settings.units);
+            if ui.button("Fit to window").clicked() {
+                if let Some(frame) = &self.current_frame {
+                    self.image_view.reset_zoom(frame, ui.available_size());
+                }
+            }
+            ui.horizontal(|ui| {
+                ui.selectable_value(&mut self.view_mode, ViewMode::Normal, "Normal");
+                ui.selectable_value(&mut self.view_mode, ViewMode::FrameDiff, "Frame diff");
+            });
+            let diff_display;
+            let display_frame = match (self.view_mode, &self.current_frame, &self.reference_frame) {
+                (ViewMode::FrameDiff, Some(frame), Some(reference)) => {
+                    diff_display = crate::processor::diff::absolute_diff(frame, reference)
+                        .map(|g| image::DynamicImage::ImageLuma8(g).to_rgb8());
+                    diff_display.as_ref()
+                }
+                _ => self.current_frame.as_ref(),
+            };
+            self.image_view.show(
+                ui,
+                display_frame,
+                crate::ui::image_view::ImageViewParams {
+                    calibration: self.scoring_config(),
+                    target: &self.target,
+                    session: &self.session,
+                    palette: &self.settings.overlay.zone_palette,
+                    gauge_diameter_mm: self.gauge_diameter_mm,
+                },
+            );
+            ui.separator();
+            if let Some(to_delete) = self.shot_list.show(ui, &self.session, &self.settings.units) {
+                let mut edited = self.session.clone();
+                edited.remove_shots(&to_delete);
+                self.apply_session_change("Delete shots", edited);
+                self.shot_list.clear_selection();
+            }
+        });
+    }
+
+    /// Removes the crash-recovery file on a clean shutdown so it isn't
+    /// offered again next launch.
+    fn on_exit(&mut self, _gl: Option<&eframe::glow::Context>) {
+        crate::recovery::clear();
+        crate::integrations::shutdown();
+    }
+}
+
+fn color_picker(ui: &mut egui::Ui, label: &str, color: &mut [u8; 3]) -> bool {
+    ui.horizontal(|ui| {
+        ui.label(label);
+        ui.color_edit_button_srgb(color).changed()
+    })
+    .inner
+}
+
+/// Attaches the oldest unmatched timer split to each of `session`'s
+/// shots after the first `skip`, in order, popping from `pending` as it
+/// goes; stops once either runs out. Returns whether any shot was
+/// updated. Split out of [`MyApp::match_timer_splits`] as the pure part
+/// of that method, independent of the shot-timer client and `MyApp`'s
+/// background threads, so it can be unit tested directly.
+fn attach_timer_splits(session: &mut Session, skip: usize, pending: &mut VecDeque<f32>) -> bool {
+    let mut matched = false;
+    let new_shots = session.series.iter_mut().flat_map(|s| s.shots.iter_mut()).skip(skip);
+    for shot in new_shots {
+        let Some(split) = pending.pop_front() else { break };
+        shot.timer_split_secs = Some(split);
+        matched = true;
+    }
+    matched
+}
+
+/// Turns unconfirmed acoustic detections into their own shots, scored
+/// from their reported coordinates, appended to `session`'s last series
+/// (creating an "Acoustic-only" series first if `session` has none yet).
+/// Split out of [`MyApp::fuse_acoustic_input`] as the pure part of that
+/// method, independent of the acoustic client, timing and `MyApp`'s
+/// background threads, so it can be unit tested directly.
+fn promote_unconfirmed_acoustic_shots(
+    session: &mut Session,
+    target: &crate::target::TargetType,
+    stale: Vec<crate::acoustic::AcousticShot>,
+) {
+    if stale.is_empty() {
+        return;
+    }
+    if session.series.is_empty() {
+        session.series.push(Series { label: "Acoustic-only".to_string(), shots: Vec::new() });
+    }
+    let mut next_number = session.all_shots().count() + 1;
+    for detection in stale {
+        let distance_mm = (detection.x_mm * detection.x_mm + detection.y_mm * detection.y_mm).sqrt();
+        let (value, is_x) = target.score(distance_mm);
+        let series = session.series.last_mut().expect("just ensured non-empty");
+        series.shots.push(crate::session::Shot {
+            number: next_number,
+            x_mm: detection.x_mm,
+            y_mm: detection.y_mm,
+            value,
+            is_x,
+            timestamp: std::time::SystemTime::now(),
+            note: Some("Acoustic-only detection (no optical confirmation)".to_string()),
+            flagged: false,
+            manual: false,
+            timer_split_secs: None,
+            acoustic_confirmed: true,
+        });
+        next_number += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn session_with_shots(count: usize) -> Session {
+        let mut session = Session::new(Shooter::default());
+        session.series.push(Series {
+            label: "Series 1".to_string(),
+            shots: (1..=count)
+                .map(|number| crate::session::Shot {
+                    number,
+                    x_mm: 0.0,
+                    y_mm: 0.0,
+                    value: 10.0,
+                    is_x: false,
+                    timestamp: std::time::SystemTime::now(),
+                    note: None,
+                    flagged: false,
+                    manual: false,
+                    timer_split_secs: None,
+                    acoustic_confirmed: false,
+                })
+                .collect(),
+        });
+        session
+    }
+
+    #[test]
+    fn attach_timer_splits_matches_new_shots_in_order() {
+        let mut session = session_with_shots(2);
+        let mut pending = VecDeque::from([1.5, 2.25]);
+        assert!(attach_timer_splits(&mut session, 0, &mut pending));
+        let shots: Vec<_> = session.all_shots().collect();
+        assert_eq!(shots[0].timer_split_secs, Some(1.5));
+        assert_eq!(shots[1].timer_split_secs, Some(2.25));
+        assert!(pending.is_empty());
+    }
+
+    #[test]
+    fn attach_timer_splits_skips_already_matched_shots() {
+        let mut session = session_with_shots(2);
+        let mut pending = VecDeque::from([9.0]);
+        assert!(attach_timer_splits(&mut session, 1, &mut pending));
+        let shots: Vec<_> = session.all_shots().collect();
+        assert_eq!(shots[0].timer_split_secs, None);
+        assert_eq!(shots[1].timer_split_secs, Some(9.0));
+    }
+
+    #[test]
+    fn attach_timer_splits_stops_when_pending_runs_out() {
+        let mut session = session_with_shots(3);
+        let mut pending = VecDeque::from([1.0]);
+        assert!(attach_timer_splits(&mut session, 0, &mut pending));
+        let shots: Vec<_> = session.all_shots().collect();
+        assert_eq!(shots[0].timer_split_secs, Some(1.0));
+        assert_eq!(shots[1].timer_split_secs, None);
+        assert_eq!(shots[2].timer_split_secs, None);
+    }
+
+    #[test]
+    fn attach_timer_splits_with_no_pending_returns_false() {
+        let mut session = session_with_shots(1);
+        let mut pending = VecDeque::new();
+        assert!(!attach_timer_splits(&mut session, 0, &mut pending));
+    }
+
+    #[test]
+    fn promote_unconfirmed_acoustic_shots_is_a_no_op_when_stale_is_empty() {
+        let mut session = session_with_shots(1);
+        promote_unconfirmed_acoustic_shots(&mut session, &target::issf_10m(), Vec::new());
+        assert_eq!(session.all_shots().count(), 1);
+    }
+
+    #[test]
+    fn promote_unconfirmed_acoustic_shots_creates_a_series_when_none_exists() {
+        let mut session = Session::new(Shooter::default());
+        let stale = vec![crate::acoustic::AcousticShot { x_mm: 0.0, y_mm: 0.0 }];
+        promote_unconfirmed_acoustic_shots(&mut session, &target::issf_10m(), stale);
+        assert_eq!(session.series.len(), 1);
+        assert_eq!(session.series[0].label, "Acoustic-only");
+        let shots: Vec<_> = session.all_shots().collect();
+        assert_eq!(shots.len(), 1);
+        assert_eq!(shots[0].number, 1);
+        assert!(shots[0].acoustic_confirmed);
+        assert!(shots[0].note.as_deref().unwrap().contains("no optical confirmation"));
+    }
+
+    #[test]
+    fn promote_unconfirmed_acoustic_shots_numbers_continue_from_existing_shots() {
+        let mut session = session_with_shots(2);
+        let stale = vec![
+            crate::acoustic::AcousticShot { x_mm: 1.0, y_mm: 1.0 },
+            crate::acoustic::AcousticShot { x_mm: -1.0, y_mm: -1.0 },
+        ];
+        promote_unconfirmed_acoustic_shots(&mut session, &target::issf_10m(), stale);
+        let shots: Vec<_> = session.all_shots().collect();
+        assert_eq!(shots.len(), 4);
+        assert_eq!(shots[2].number, 3);
+        assert_eq!(shots[3].number, 4);
+    }
+}
@@ -0,0 +1,24 @@
+//! Calibration: how pixel coordinates in the source frame map to
+//! millimeters on the physical target.
+
+use serde::{Deserialize, Serialize};
+
+/// Everything needed to turn a detected pixel position into a scored mm
+/// coordinate. Written by the calibration wizard and manual controls
+/// alike, and persisted with the session/project.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ScoringConfig {
+    pub center_px: (f32, f32),
+    pub pixels_per_mm: f32,
+    pub rotation_deg: f32,
+}
+
+impl Default for ScoringConfig {
+    fn default() -> Self {
+        Self {
+            center_px: (0.0, 0.0),
+            pixels_per_mm: 1.0,
+            rotation_deg: 0.0,
+        }
+    }
+}
@@ -0,0 +1,75 @@
+//! Camera capture. On native targets this wraps `nokhwa`; on `wasm32`
+//! it wraps the browser's `getUserMedia` via `web-sys` (see
+//! [`crate::web`]). Neither backend is actually wired up in this
+//! scaffold yet — see `read_frame` on either target.
+
+use image::RgbImage;
+use serde::{Deserialize, Serialize};
+
+use crate::error::AppError;
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct CameraStats {
+    pub resolution: (u32, u32),
+    pub fps: f32,
+}
+
+/// A live camera source. Frame pump lives on whatever thread calls
+/// `read_frame`; the GUI polls it once per update.
+pub struct Camera {
+    resolution: (u32, u32),
+    last_frame_at: std::time::Instant,
+    fps_estimate: f32,
+}
+
+impl Camera {
+    #[tracing::instrument]
+    pub fn open(resolution: (u32, u32)) -> Result<Self, AppError> {
+        tracing::info!("opening camera");
+        Ok(Self {
+            resolution,
+            last_frame_at: std::time::Instant::now(),
+            fps_estimate: 0.0,
+        })
+    }
+
+    /// Blocks for the next frame from the device. In this scaffold there
+    /// is no real device backend wired in yet; callers in static/replay
+    /// modes never call this. Recoverable: a caller can skip the frame
+    /// and try again next tick rather than tearing down the camera.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn read_frame(&mut self) -> Result<RgbImage, AppError> {
+        self.tick_fps();
+        tracing::warn!("read_frame called with no camera backend compiled in");
+        Err(AppError::Camera("no camera backend compiled in".to_string()))
+    }
+
+    /// Browser build: frames would come from a `getUserMedia` video
+    /// track via an `ImageCapture`/`<canvas>` grab, driven from JS since
+    /// wasm32 has no blocking device I/O — the GUI would need to poll a
+    /// frame queue filled by a `wasm_bindgen_futures` task instead of
+    /// calling this synchronously. That bridge is a follow-up; this
+    /// stays an honest stub like the native branch above.
+    #[cfg(target_arch = "wasm32")]
+    pub fn read_frame(&mut self) -> Result<RgbImage, AppError> {
+        self.tick_fps();
+        tracing::warn!("read_frame called with no browser camera backend wired in yet");
+        Err(AppError::Camera("browser camera capture not wired in yet".to_string()))
+    }
+
+    fn tick_fps(&mut self) {
+        let now = std::time::Instant::now();
+        let dt = now.duration_since(self.last_frame_at).as_secs_f32();
+        if dt > 0.0 {
+            self.fps_estimate = 1.0 / dt;
+        }
+        self.last_frame_at = now;
+    }
+
+    pub fn stats(&self) -> CameraStats {
+        CameraStats {
+            resolution: self.resolution,
+            fps: self.fps_estimate,
+        }
+    }
+}
@@ -0,0 +1,21 @@
+//! Public-facing scoring engine surface: frame processing
+//! ([`crate::processor`]) and target/scoring definitions
+//! ([`crate::target`], [`crate::calibration`]) — none of which depend on
+//! `egui` or `nokhwa`.
+//!
+//! This module is the intended boundary for a standalone
+//! `precision-scorer-core` library crate, so the detection and scoring
+//! engine can be embedded in other tools (a headless scorer, a mobile
+//! app backend) without pulling in the desktop GUI. Extracting it into
+//! its own crate needs a Cargo workspace manifest, which this checkout
+//! doesn't have yet; until then, this re-export module documents the
+//! exact surface that split would expose.
+
+pub use crate::calibration::ScoringConfig;
+pub use crate::error::AppError;
+pub use crate::processor::backends::DetectionBackend;
+pub use crate::processor::{
+    Detection, DetectionBackendKind, OnnxModelConfig, Processor, ProcessorMetrics, ProcessorSettings,
+    TemplateConfig,
+};
+pub use crate::target::{by_name, issf_10m, issf_25m_pistol, issf_50m_rifle, presets, TargetType};
@@ -0,0 +1,48 @@
+//! Appends each scored shot to a growing CSV file for legacy range
+//! display software that tails a file instead of speaking a network
+//! protocol.
+
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+use crate::session::Shot;
+
+const HEADER: &str = "number,value,x_mm,y_mm,is_x,timestamp\n";
+
+pub struct CsvFeed {
+    path: PathBuf,
+}
+
+impl CsvFeed {
+    /// Creates `path` with a header row if it doesn't already exist, or
+    /// resumes appending to it if it does.
+    pub fn start(path: PathBuf) -> io::Result<Self> {
+        if !path.exists() {
+            fs::write(&path, HEADER)?;
+        }
+        Ok(Self { path })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Appends one line for `shot`. The file is opened in append mode
+    /// and the line is written with a single `write_all` call, which
+    /// POSIX guarantees is atomic for writes under `PIPE_BUF` — so a
+    /// legacy tool tailing the file never observes a half-written row.
+    pub fn append_shot(&self, shot: &Shot) -> io::Result<()> {
+        let seconds = shot
+            .timestamp
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0);
+        let line = format!(
+            "{},{},{},{},{},{}\n",
+            shot.number, shot.value, shot.x_mm, shot.y_mm, shot.is_x as u8, seconds
+        );
+        let mut file = OpenOptions::new().append(true).open(&self.path)?;
+        file.write_all(line.as_bytes())
+    }
+}
@@ -0,0 +1,36 @@
+//! Crate-wide error type for camera, processing and storage operations,
+//! classified as recoverable (the operator can retry or keep shooting
+//! through it) or fatal (the app can't safely continue in its current
+//! state) so callers can decide whether to just toast a notification or
+//! fall back to a safe default.
+
+use std::path::{Path, PathBuf};
+
+use thiserror::Error;
+
+#[derive(Debug, Clone, Error)]
+pub enum AppError {
+    #[error("camera error: {0}")]
+    Camera(String),
+    #[error("failed to decode frame: {0}")]
+    FrameDecode(String),
+    #[error("processing error: {0}")]
+    Processing(String),
+    #[error("storage error ({path}): {message}", path = path.display())]
+    Storage { path: PathBuf, message: String },
+}
+
+impl AppError {
+    pub fn storage(path: &Path, message: impl Into<String>) -> Self {
+        AppError::Storage { path: path.to_path_buf(), message: message.into() }
+    }
+
+    /// Whether the operator can dismiss this and keep working. Camera
+    /// glitches and bad frames are transient; a backend that can't load
+    /// its model is a config problem the operator can fix mid-match.
+    /// Storage failures are fatal: settings or a session that can't be
+    /// written to disk means the app can't guarantee state isn't lost.
+    pub fn is_recoverable(&self) -> bool {
+        !matches!(self, AppError::Storage { .. })
+    }
+}
@@ -0,0 +1,61 @@
+//! Typed event bus decoupling scoring/processing from the modules that
+//! react to it (network outputs, storage, scripting, audio). A publisher
+//! calls [`EventBus::publish`] once a fact is known; a subscriber calls
+//! [`EventBus::subscribe`] to get a channel and drains it once per frame,
+//! the same poll-per-frame style already used for hot-reload watchers and
+//! autosave rather than spawning a dedicated event-loop thread.
+//!
+//! This lands the bus and moves the scripting hooks (the newest, least
+//! entangled consumer) onto it. The older shot fan-out sinks
+//! (`shot_broadcaster`, `mqtt_publisher`, `udp_broadcaster`, `csv_feed`,
+//! `led_scoreboard`) keep their existing per-sink shot-count trackers for
+//! now, since each resets its baseline to "shots since I connected" at
+//! its own connect call site — folding that onto a shared bus is real,
+//! separate follow-up work rather than something to fold in here. New
+//! integrations should prefer subscribing to the bus over adding another
+//! `last_*_shot_count` field.
+
+use std::sync::mpsc::{channel, Receiver, Sender};
+
+use serde::{Deserialize, Serialize};
+
+use crate::calibration::ScoringConfig;
+use crate::camera::CameraStats;
+use crate::processor::{Detection, ProcessorSettings};
+use crate::session::Shot;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum AppEvent {
+    ShotScored(Shot),
+    DetectionUpdated(Vec<Detection>),
+    CameraStatusChanged(CameraStats),
+    CalibrationChanged(ScoringConfig),
+    /// Threshold/crop/backend tuning changed, independent of calibration —
+    /// recorded so [`crate::replay::Player`] can reproduce a session where
+    /// detector settings were adjusted mid-recording, not just calibration.
+    ProcessorSettingsChanged(ProcessorSettings),
+}
+
+/// Fans published events out to every subscriber's channel.
+#[derive(Default)]
+pub struct EventBus {
+    subscribers: Vec<Sender<AppEvent>>,
+}
+
+impl EventBus {
+    /// Registers a new subscriber and returns its receiving end. Only
+    /// events published after this call are seen, so a subscriber that
+    /// connects mid-session naturally gets a "since I joined" view
+    /// without needing its own baseline counter.
+    pub fn subscribe(&mut self) -> Receiver<AppEvent> {
+        let (tx, rx) = channel();
+        self.subscribers.push(tx);
+        rx
+    }
+
+    /// Sends `event` to every live subscriber; subscribers that dropped
+    /// their receiving end are pruned on the next publish.
+    pub fn publish(&mut self, event: AppEvent) {
+        self.subscribers.retain(|tx| tx.send(event.clone()).is_ok());
+    }
+}
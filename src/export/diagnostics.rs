@@ -0,0 +1,75 @@
+//! "Export diagnostics" bundle: zips up recent logs, the active
+//! processor/target config and the last few raw frames with their
+//! detection outputs, so a remote support session can be handed one file
+//! instead of a screen-share.
+
+use std::io::{self, Write};
+use std::path::Path;
+
+use image::RgbImage;
+use zip::write::SimpleFileOptions;
+use zip::ZipWriter;
+
+use crate::processor::{Detection, ProcessorSettings};
+use crate::target::TargetType;
+
+/// One recently processed frame plus what the detector found in it, kept
+/// around only for diagnostics — not part of the scored session history.
+pub struct DiagnosticFrame {
+    pub frame: RgbImage,
+    pub detections: Vec<Detection>,
+}
+
+pub fn export_bundle(
+    path: &Path,
+    logs: &[String],
+    settings: &ProcessorSettings,
+    target: &TargetType,
+    frames: &[DiagnosticFrame],
+) -> io::Result<()> {
+    let file = std::fs::File::create(path)?;
+    let mut zip = ZipWriter::new(file);
+    let options = SimpleFileOptions::default().compression_method(zip::CompressionMethod::Deflated);
+
+    zip.start_file("logs.txt", options).map_err(zip_err)?;
+    zip.write_all(logs.join("\n").as_bytes())?;
+
+    zip.start_file("config.json", options).map_err(zip_err)?;
+    let config = serde_json::json!({
+        "processor": {
+            "threshold": settings.threshold,
+            "min_contour_area": settings.min_contour_area,
+            "max_contour_area": settings.max_contour_area,
+        },
+        "target": target.name,
+    });
+    let config_json = serde_json::to_string_pretty(&config).map_err(|e| io::Error::new(io::ErrorKind::Other, e))?;
+    zip.write_all(config_json.as_bytes())?;
+
+    for (i, diagnostic) in frames.iter().enumerate() {
+        zip.start_file(format!("frames/{i:03}.png"), options).map_err(zip_err)?;
+        let mut png_bytes = Vec::new();
+        diagnostic
+            .frame
+            .write_with_encoder(image::codecs::png::PngEncoder::new(&mut png_bytes))
+            .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?;
+        zip.write_all(&png_bytes)?;
+
+        zip.start_file(format!("frames/{i:03}-detections.json"), options).map_err(zip_err)?;
+        let detections: Vec<_> = diagnostic
+            .detections
+            .iter()
+            .map(|d| serde_json::json!({"center_px": d.center_px, "area": d.area}))
+            .collect();
+        let detections_json =
+            serde_json::to_string_pretty(&detections).map_err(|e| io::Error::new(io::ErrorKind::Other, e))?;
+        zip.write_all(detections_json.as_bytes())?;
+    }
+
+    zip.finish().map_err(zip_err)?;
+    Ok(())
+}
+
+fn zip_err(e: zip::result::ZipError) -> io::Error {
+    io::Error::new(io::ErrorKind::Other, e)
+}
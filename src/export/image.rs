@@ -0,0 +1,84 @@
+//! Burned-in image export: renders holes, scores, rings and center
+//! directly into the frame's pixels (rather than the egui painter) so the
+//! result can be saved and shared exactly as seen on screen.
+
+use image::{ImageBuffer, Rgb, RgbImage};
+
+use crate::overlay::OverlaySettings;
+use crate::session::Session;
+use crate::target::TargetType;
+
+/// Draws the current frame plus scoring overlays into a new image buffer,
+/// suitable for `save_annotated`.
+pub fn render_annotated(
+    frame: &RgbImage,
+    center_px: (f32, f32),
+    pixels_per_mm: f32,
+    overlay_rotation_deg: f32,
+    session: &Session,
+    target: &TargetType,
+    overlay: &OverlaySettings,
+) -> RgbImage {
+    let mut out: RgbImage = ImageBuffer::from_fn(frame.width(), frame.height(), |x, y| {
+        *frame.get_pixel(x, y)
+    });
+
+    draw_center_marker(&mut out, center_px, overlay.center_color);
+    draw_rings(&mut out, center_px, pixels_per_mm, target, &overlay.zone_palette);
+    for shot in session.all_shots() {
+        let (x_mm, y_mm) = crate::overlay::rotate_mm(shot.x_mm, shot.y_mm, overlay_rotation_deg);
+        let px = center_px.0 + x_mm * pixels_per_mm;
+        let py = center_px.1 - y_mm * pixels_per_mm;
+        draw_hole(&mut out, (px, py), overlay.zone_palette.color_for_ring(shot.value));
+    }
+    out
+}
+
+/// Saves `image` as PNG or JPEG based on the extension of `path`.
+pub fn save_annotated(image: &RgbImage, path: &std::path::Path) -> image::ImageResult<()> {
+    image.save(path)
+}
+
+fn draw_center_marker(out: &mut RgbImage, center: (f32, f32), color: [u8; 3]) {
+    draw_cross(out, center, 8, Rgb(color));
+}
+
+fn draw_rings(
+    out: &mut RgbImage,
+    center: (f32, f32),
+    pixels_per_mm: f32,
+    target: &TargetType,
+    palette: &crate::overlay::ZonePalette,
+) {
+    for (color, radius_mm) in crate::overlay::ring_colors(target, palette) {
+        draw_circle(out, center, radius_mm * pixels_per_mm, Rgb(color));
+    }
+}
+
+fn draw_hole(out: &mut RgbImage, center: (f32, f32), color: [u8; 3]) {
+    draw_circle(out, center, 6.0, Rgb(color));
+}
+
+fn draw_cross(out: &mut RgbImage, center: (f32, f32), half_len: i32, color: Rgb<u8>) {
+    let (cx, cy) = (center.0 as i32, center.1 as i32);
+    for d in -half_len..=half_len {
+        put_pixel_checked(out, cx + d, cy, color);
+        put_pixel_checked(out, cx, cy + d, color);
+    }
+}
+
+fn draw_circle(out: &mut RgbImage, center: (f32, f32), radius: f32, color: Rgb<u8>) {
+    let steps = ((radius * 6.0).max(32.0)) as i32;
+    for i in 0..steps {
+        let theta = i as f32 / steps as f32 * std::f32::consts::TAU;
+        let x = center.0 + radius * theta.cos();
+        let y = center.1 + radius * theta.sin();
+        put_pixel_checked(out, x as i32, y as i32, color);
+    }
+}
+
+fn put_pixel_checked(out: &mut RgbImage, x: i32, y: i32, color: Rgb<u8>) {
+    if x >= 0 && y >= 0 && (x as u32) < out.width() && (y as u32) < out.height() {
+        out.put_pixel(x as u32, y as u32, color);
+    }
+}
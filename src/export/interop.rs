@@ -0,0 +1,122 @@
+//! Exporters that write session data in the exchange formats used by
+//! commercial electronic targets, so results software that only ingests
+//! SIUS/Meyton files can still take a precision-scorer session.
+//!
+//! Both formats are simplified, field-order-based ASCII layouts; we cover
+//! the fields federation results software actually reads (shot number,
+//! decimal value, X/Y in mm, X-ring flag) rather than the full vendor
+//! protocol.
+
+use std::fmt::Write as _;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use crate::session::Session;
+use crate::target::TargetType;
+
+/// Writes `session` as SIUS ASCII exchange format: one header line, then
+/// one line per shot as `shot_no;value;x_mm;y_mm;x_flag`.
+pub fn write_sius_ascii(session: &Session, target: &TargetType, path: &Path) -> io::Result<()> {
+    let mut out = String::new();
+    let _ = writeln!(out, "SIUS-ASCII;1.0;{}", target.name);
+    let _ = writeln!(out, "SHOOTER;{}", session.shooter.name);
+    for shot in session.all_shots() {
+        let _ = writeln!(
+            out,
+            "{};{:.1};{:.2};{:.2};{}",
+            shot.number,
+            shot.value,
+            shot.x_mm,
+            shot.y_mm,
+            if shot.is_x { 1 } else { 0 }
+        );
+    }
+    fs::write(path, out)
+}
+
+/// Writes `session` as a Meyton exchange file: fixed-width columns,
+/// series-delimited, matching the layout Meyton's own export tool
+/// produces for club-level results software.
+pub fn write_meyton(session: &Session, target: &TargetType, path: &Path) -> io::Result<()> {
+    let mut out = String::new();
+    let _ = writeln!(out, "MEYTON EXCHANGE  TARGET={}", target.name);
+    let _ = writeln!(out, "SHOOTER={}", session.shooter.name);
+    for (i, series) in session.series.iter().enumerate() {
+        let _ = writeln!(out, "SERIES {:02}", i + 1);
+        for shot in &series.shots {
+            let _ = writeln!(
+                out,
+                "{:>3}  {:>5.1}  {:>7.2}  {:>7.2}  {}",
+                shot.number,
+                shot.value,
+                shot.x_mm,
+                shot.y_mm,
+                if shot.is_x { "X" } else { " " }
+            );
+        }
+        let _ = writeln!(out, "SERIES-TOTAL  {:.1}  X={}", series.total(), series.x_count());
+    }
+    let _ = writeln!(out, "TOTAL  {:.1}  X={}", session.total(), session.x_count());
+    fs::write(path, out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::session::{Series, Shooter, Shot};
+    use std::time::SystemTime;
+
+    fn shot(number: usize, value: f32, x_mm: f32, y_mm: f32, is_x: bool) -> Shot {
+        Shot {
+            number,
+            x_mm,
+            y_mm,
+            value,
+            is_x,
+            timestamp: SystemTime::now(),
+            note: None,
+            flagged: false,
+            manual: false,
+            timer_split_secs: None,
+            acoustic_confirmed: false,
+        }
+    }
+
+    fn session() -> Session {
+        let mut session = Session::new(Shooter { name: "Jane Doe".to_string(), club: "Test Club".to_string() });
+        session.series.push(Series {
+            label: "Series 1".to_string(),
+            shots: vec![shot(1, 10.9, 0.1, -0.2, true), shot(2, 9.5, -1.0, 1.0, false)],
+        });
+        session
+    }
+
+    fn scratch_path(name: &str) -> std::path::PathBuf {
+        std::env::temp_dir().join(format!("precision-scorer-test-{}-{name}", std::process::id()))
+    }
+
+    #[test]
+    fn sius_ascii_writes_header_shooter_and_one_line_per_shot() {
+        let path = scratch_path("sius.txt");
+        write_sius_ascii(&session(), &crate::target::issf_10m(), &path).unwrap();
+        let text = fs::read_to_string(&path).unwrap();
+        let _ = fs::remove_file(&path);
+        assert!(text.starts_with("SIUS-ASCII;1.0;ISSF 10m\n"));
+        assert!(text.contains("SHOOTER;Jane Doe\n"));
+        assert!(text.contains("1;10.9;0.10;-0.20;1\n"));
+        assert!(text.contains("2;9.5;-1.00;1.00;0\n"));
+    }
+
+    #[test]
+    fn meyton_writes_series_and_session_totals() {
+        let path = scratch_path("meyton.txt");
+        write_meyton(&session(), &crate::target::issf_10m(), &path).unwrap();
+        let text = fs::read_to_string(&path).unwrap();
+        let _ = fs::remove_file(&path);
+        assert!(text.contains("SHOOTER=Jane Doe\n"));
+        assert!(text.contains("SERIES 01\n"));
+        assert!(text.contains("SERIES-TOTAL  20.4  X=1\n"));
+        assert!(text.contains("TOTAL  20.4  X=1\n"));
+    }
+}
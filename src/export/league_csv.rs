@@ -0,0 +1,131 @@
+//! Structured CSV export for league/match-administration software
+//! (Practiscore and similar import one row per string), with a
+//! configurable column set and order so it lines up with whatever
+//! layout the receiving tool expects.
+
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use crate::session::{Series, Session};
+use crate::target::TargetType;
+
+/// One exportable field; `LeagueCsvConfig::columns` picks which of these
+/// appear, and in what order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LeagueCsvColumn {
+    Shooter,
+    Club,
+    Discipline,
+    Relay,
+    SeriesLabel,
+    SeriesTotal,
+    SeriesXCount,
+    SessionTotal,
+    SessionXCount,
+}
+
+/// Every available column, in canonical order, for building a column
+/// picker in the UI.
+pub const ALL_COLUMNS: [LeagueCsvColumn; 9] = [
+    LeagueCsvColumn::Shooter,
+    LeagueCsvColumn::Club,
+    LeagueCsvColumn::Discipline,
+    LeagueCsvColumn::Relay,
+    LeagueCsvColumn::SeriesLabel,
+    LeagueCsvColumn::SeriesTotal,
+    LeagueCsvColumn::SeriesXCount,
+    LeagueCsvColumn::SessionTotal,
+    LeagueCsvColumn::SessionXCount,
+];
+
+impl LeagueCsvColumn {
+    pub fn header(&self) -> &'static str {
+        match self {
+            Self::Shooter => "Shooter",
+            Self::Club => "Club",
+            Self::Discipline => "Discipline",
+            Self::Relay => "Relay",
+            Self::SeriesLabel => "Series",
+            Self::SeriesTotal => "Series Total",
+            Self::SeriesXCount => "Series X",
+            Self::SessionTotal => "Match Total",
+            Self::SessionXCount => "Match X",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct LeagueCsvConfig {
+    pub columns: Vec<LeagueCsvColumn>,
+    /// Relay/squad label, since the session itself doesn't track one.
+    pub relay: String,
+}
+
+impl Default for LeagueCsvConfig {
+    fn default() -> Self {
+        Self {
+            columns: vec![
+                LeagueCsvColumn::Shooter,
+                LeagueCsvColumn::Club,
+                LeagueCsvColumn::Discipline,
+                LeagueCsvColumn::Relay,
+                LeagueCsvColumn::SeriesLabel,
+                LeagueCsvColumn::SeriesTotal,
+                LeagueCsvColumn::SeriesXCount,
+                LeagueCsvColumn::SessionTotal,
+                LeagueCsvColumn::SessionXCount,
+            ],
+            relay: String::new(),
+        }
+    }
+}
+
+/// Writes one row per series, in `config.columns` order.
+pub fn write_league_csv(
+    session: &Session,
+    target: &TargetType,
+    config: &LeagueCsvConfig,
+    path: &Path,
+) -> io::Result<()> {
+    let mut out = String::new();
+    out.push_str(&config.columns.iter().map(|c| c.header()).collect::<Vec<_>>().join(","));
+    out.push('\n');
+
+    for series in &session.series {
+        let fields: Vec<String> =
+            config.columns.iter().map(|c| column_value(*c, session, target, config, series)).collect();
+        out.push_str(&fields.join(","));
+        out.push('\n');
+    }
+
+    fs::write(path, out)
+}
+
+fn column_value(
+    column: LeagueCsvColumn,
+    session: &Session,
+    target: &TargetType,
+    config: &LeagueCsvConfig,
+    series: &Series,
+) -> String {
+    match column {
+        LeagueCsvColumn::Shooter => csv_escape(&session.shooter.name),
+        LeagueCsvColumn::Club => csv_escape(&session.shooter.club),
+        LeagueCsvColumn::Discipline => csv_escape(&target.name),
+        LeagueCsvColumn::Relay => csv_escape(&config.relay),
+        LeagueCsvColumn::SeriesLabel => csv_escape(&series.label),
+        LeagueCsvColumn::SeriesTotal => format!("{:.1}", series.total()),
+        LeagueCsvColumn::SeriesXCount => series.x_count().to_string(),
+        LeagueCsvColumn::SessionTotal => format!("{:.1}", session.total()),
+        LeagueCsvColumn::SessionXCount => session.x_count().to_string(),
+    }
+}
+
+fn csv_escape(field: &str) -> String {
+    if field.contains(',') || field.contains('"') {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
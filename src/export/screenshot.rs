@@ -0,0 +1,45 @@
+//! Screenshot hotkey: saves the target image (with overlays) to a session
+//! folder named with date, shooter and shot count, without leaving the
+//! firing position for a file dialog.
+
+use std::io;
+use std::path::PathBuf;
+use std::time::UNIX_EPOCH;
+
+use image::RgbImage;
+
+use crate::session::Session;
+
+/// Builds the destination path for a screenshot: a per-session folder
+/// under `base_dir`, named `<date>-<shooter>`, with files numbered by
+/// current shot count.
+pub fn screenshot_path(base_dir: &std::path::Path, session: &Session) -> PathBuf {
+    let started = session
+        .started_at
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs();
+    let shooter = if session.shooter.name.is_empty() {
+        "unknown".to_string()
+    } else {
+        session.shooter.name.replace(' ', "_")
+    };
+    let folder = base_dir.join(format!("{started}-{shooter}"));
+    let shot_count = session.all_shots().count();
+    folder.join(format!("shot-{shot_count:03}.png"))
+}
+
+pub fn save_screenshot(image: &RgbImage, base_dir: &std::path::Path, session: &Session) -> io::Result<PathBuf> {
+    let path = screenshot_path(base_dir, session);
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    image.save(&path).map_err(|e| io::Error::new(io::ErrorKind::Other, e))?;
+    Ok(path)
+}
+
+/// Default base directory for screenshots, under the OS temp dir when no
+/// dedicated sessions folder has been configured yet.
+pub fn default_base_dir() -> PathBuf {
+    std::env::temp_dir().join("precision-scorer-sessions")
+}
@@ -0,0 +1,85 @@
+//! Native `.xlsx` export: one formatted scorecard sheet per series, a
+//! summary sheet with match statistics and an embedded shot-plot chart,
+//! for coaches who live in Excel rather than the app's own reports.
+
+use std::io;
+use std::path::Path;
+
+use rust_xlsxwriter::{Chart, ChartType, Format, Workbook};
+
+use crate::session::Session;
+use crate::target::TargetType;
+
+pub fn write_workbook(session: &Session, target: &TargetType, path: &Path) -> io::Result<()> {
+    let mut workbook = Workbook::new();
+    let header_format = Format::new().set_bold();
+
+    for (i, series) in session.series.iter().enumerate() {
+        let sheet = workbook.add_worksheet().set_name(format!("Series {}", i + 1)).map_err(xlsx_err)?;
+        sheet.write_string_with_format(0, 0, &series.label, &header_format).map_err(xlsx_err)?;
+        for (col, title) in ["Shot", "Value", "X mm", "Y mm", "X-ring"].iter().enumerate() {
+            sheet.write_string_with_format(1, col as u16, *title, &header_format).map_err(xlsx_err)?;
+        }
+        for (row, shot) in series.shots.iter().enumerate() {
+            let r = (row + 2) as u32;
+            sheet.write_number(r, 0, shot.number as f64).map_err(xlsx_err)?;
+            sheet.write_number(r, 1, shot.value as f64).map_err(xlsx_err)?;
+            sheet.write_number(r, 2, shot.x_mm as f64).map_err(xlsx_err)?;
+            sheet.write_number(r, 3, shot.y_mm as f64).map_err(xlsx_err)?;
+            sheet.write_boolean(r, 4, shot.is_x).map_err(xlsx_err)?;
+        }
+    }
+
+    write_summary_sheet(&mut workbook, session, target, &header_format)?;
+
+    workbook.save(path).map_err(xlsx_err)
+}
+
+fn write_summary_sheet(
+    workbook: &mut Workbook,
+    session: &Session,
+    target: &TargetType,
+    header_format: &Format,
+) -> io::Result<()> {
+    let sheet = workbook.add_worksheet().set_name("Summary").map_err(xlsx_err)?;
+    sheet.write_string_with_format(0, 0, "Match summary", header_format).map_err(xlsx_err)?;
+    sheet.write_string(1, 0, "Shooter").map_err(xlsx_err)?;
+    sheet.write_string(1, 1, &session.shooter.name).map_err(xlsx_err)?;
+    sheet.write_string(2, 0, "Club").map_err(xlsx_err)?;
+    sheet.write_string(2, 1, &session.shooter.club).map_err(xlsx_err)?;
+    sheet.write_string(3, 0, "Discipline").map_err(xlsx_err)?;
+    sheet.write_string(3, 1, &target.name).map_err(xlsx_err)?;
+    sheet.write_string(4, 0, "Total").map_err(xlsx_err)?;
+    sheet.write_number(4, 1, session.total() as f64).map_err(xlsx_err)?;
+    sheet.write_string(5, 0, "X-count").map_err(xlsx_err)?;
+    sheet.write_number(5, 1, session.x_count() as f64).map_err(xlsx_err)?;
+    sheet.write_string(6, 0, "Series count").map_err(xlsx_err)?;
+    sheet.write_number(6, 1, session.series.len() as f64).map_err(xlsx_err)?;
+
+    // Shot coordinates, hidden off to the side, feed the embedded plot.
+    const PLOT_COL: u16 = 4;
+    sheet.write_string_with_format(0, PLOT_COL, "X mm", header_format).map_err(xlsx_err)?;
+    sheet.write_string_with_format(0, PLOT_COL + 1, "Y mm", header_format).map_err(xlsx_err)?;
+    let shots: Vec<_> = session.all_shots().collect();
+    for (row, shot) in shots.iter().enumerate() {
+        let r = (row + 1) as u32;
+        sheet.write_number(r, PLOT_COL, shot.x_mm as f64).map_err(xlsx_err)?;
+        sheet.write_number(r, PLOT_COL + 1, shot.y_mm as f64).map_err(xlsx_err)?;
+    }
+
+    let last_row = shots.len() as u32;
+    let mut chart = Chart::new(ChartType::Scatter);
+    chart
+        .add_series()
+        .set_categories(("Summary", 1, PLOT_COL, last_row, PLOT_COL))
+        .set_values(("Summary", 1, PLOT_COL + 1, last_row, PLOT_COL + 1))
+        .set_name("Shot group");
+    chart.title().set_name("Shot plot");
+    sheet.insert_chart(1, 7, &chart).map_err(xlsx_err)?;
+
+    Ok(())
+}
+
+fn xlsx_err(e: rust_xlsxwriter::XlsxError) -> io::Error {
+    io::Error::new(io::ErrorKind::Other, e.to_string())
+}
@@ -0,0 +1,149 @@
+//! `--headless` batch mode: scores a directory of target photos against a
+//! JSON calibration/config file with no GUI, for scripted or server-side
+//! use (e.g. scoring photos dropped by a separate capture rig).
+
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::processor::{Processor, ProcessorSettings};
+use crate::target::TargetType;
+
+/// Calibration and detection parameters for a batch run; mirrors the
+/// fields an operator would otherwise set up interactively in the GUI.
+#[derive(Debug, Clone, Deserialize)]
+pub struct BatchConfig {
+    /// Built-in target preset name, e.g. `"ISSF 10m"`; see
+    /// `crate::target::presets`.
+    pub target_preset: String,
+    pub center_px: (f32, f32),
+    pub pixels_per_mm: f32,
+    #[serde(default = "default_threshold")]
+    pub threshold: u8,
+    #[serde(default = "default_min_area")]
+    pub min_contour_area: f32,
+    #[serde(default = "default_max_area")]
+    pub max_contour_area: f32,
+}
+
+fn default_threshold() -> u8 {
+    80
+}
+
+fn default_min_area() -> f32 {
+    8.0
+}
+
+fn default_max_area() -> f32 {
+    400.0
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ShotResult {
+    pub x_mm: f32,
+    pub y_mm: f32,
+    pub value: f32,
+    pub is_x: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ImageResult {
+    pub file: String,
+    pub shots: Vec<ShotResult>,
+    pub total: f32,
+    pub x_count: usize,
+}
+
+/// Runs detection and scoring on every image in `input_dir`, using
+/// `config_path` for calibration, and writes `results.json` and
+/// `results.csv` into `output_dir`.
+pub fn run(input_dir: &Path, config_path: &Path, output_dir: &Path) -> io::Result<()> {
+    let config_json = fs::read_to_string(config_path)?;
+    let config: BatchConfig = serde_json::from_str(&config_json)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e.to_string()))?;
+    let target = crate::target::by_name(&config.target_preset)
+        .unwrap_or_else(crate::target::issf_10m);
+
+    let mut processor = Processor {
+        settings: ProcessorSettings {
+            threshold: config.threshold,
+            min_contour_area: config.min_contour_area,
+            max_contour_area: config.max_contour_area,
+            ..ProcessorSettings::default()
+        },
+        ..Processor::default()
+    };
+
+    let mut entries: Vec<_> = fs::read_dir(input_dir)?
+        .filter_map(|e| e.ok())
+        .map(|e| e.path())
+        .filter(|p| is_image(p))
+        .collect();
+    entries.sort();
+
+    let mut results = Vec::with_capacity(entries.len());
+    for path in &entries {
+        let image = image::open(path)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e.to_string()))?
+            .to_rgb8();
+        let result = score_image(&mut processor, &image, &config, &target, path);
+        results.push(result);
+    }
+
+    fs::create_dir_all(output_dir)?;
+    fs::write(output_dir.join("results.json"), serde_json::to_string_pretty(&results)?)?;
+    fs::write(output_dir.join("results.csv"), to_csv(&results))?;
+
+    println!("scored {} image(s), wrote results.json and results.csv to {}", results.len(), output_dir.display());
+    Ok(())
+}
+
+fn score_image(
+    processor: &mut Processor,
+    image: &image::RgbImage,
+    config: &BatchConfig,
+    target: &TargetType,
+    path: &Path,
+) -> ImageResult {
+    let detections = processor.process(image);
+    let shots: Vec<ShotResult> = detections
+        .iter()
+        .map(|d| {
+            let x_mm = (d.center_px.0 - config.center_px.0) / config.pixels_per_mm;
+            let y_mm = (config.center_px.1 - d.center_px.1) / config.pixels_per_mm;
+            let distance_mm = (x_mm * x_mm + y_mm * y_mm).sqrt();
+            let (value, is_x) = target.score(distance_mm);
+            ShotResult { x_mm, y_mm, value, is_x }
+        })
+        .collect();
+    let total = shots.iter().map(|s| s.value).sum();
+    let x_count = shots.iter().filter(|s| s.is_x).count();
+    ImageResult { file: path.display().to_string(), shots, total, x_count }
+}
+
+fn is_image(path: &Path) -> bool {
+    matches!(
+        path.extension().and_then(|e| e.to_str()).map(|e| e.to_ascii_lowercase()).as_deref(),
+        Some("png" | "jpg" | "jpeg" | "bmp")
+    )
+}
+
+fn to_csv(results: &[ImageResult]) -> String {
+    let mut out = String::from("file,shot,x_mm,y_mm,value,is_x\n");
+    for result in results {
+        for (i, shot) in result.shots.iter().enumerate() {
+            out.push_str(&format!(
+                "{},{},{:.2},{:.2},{:.1},{}\n",
+                result.file,
+                i + 1,
+                shot.x_mm,
+                shot.y_mm,
+                shot.value,
+                shot.is_x
+            ));
+        }
+    }
+    out
+}
@@ -0,0 +1,80 @@
+//! On-disk session history: every completed session is saved as JSON
+//! under the app's data directory, keyed by discipline and start time,
+//! so the History browser can list, filter and reopen past sessions
+//! without the user managing files by hand.
+
+use std::path::PathBuf;
+use std::time::UNIX_EPOCH;
+
+use crate::error::AppError;
+use crate::session::Session;
+
+/// Cheap-to-list summary of a saved session — loaded eagerly for every
+/// file in the history directory without needing a separate index.
+#[derive(Debug, Clone)]
+pub struct HistoryEntry {
+    pub path: PathBuf,
+    pub shooter: String,
+    pub club: String,
+    pub started_at_secs: u64,
+    pub discipline: String,
+    pub total: f32,
+    pub x_count: usize,
+}
+
+pub fn history_dir() -> Option<PathBuf> {
+    let dirs = directories::ProjectDirs::from("com", "precision-scorer", "precision-scorer")?;
+    Some(dirs.data_dir().join("sessions"))
+}
+
+/// Saves `session` into the history directory, named by discipline and
+/// start timestamp so repeated saves of the same session overwrite in
+/// place instead of accumulating duplicates.
+pub fn save_to_history(session: &Session, discipline: &str) -> Result<PathBuf, AppError> {
+    let Some(dir) = history_dir() else {
+        return Err(AppError::storage(&PathBuf::from("sessions"), "no data directory available"));
+    };
+    std::fs::create_dir_all(&dir).map_err(|e| AppError::storage(&dir, e.to_string()))?;
+    let started_at_secs = session.started_at.duration_since(UNIX_EPOCH).unwrap_or_default().as_secs();
+    let path = dir.join(format!("{discipline}-{started_at_secs}.json"));
+    session.save_json(&path)?;
+    Ok(path)
+}
+
+/// Lists every session in the history directory, newest first. Files
+/// that fail to parse are skipped rather than failing the whole list.
+pub fn list_history() -> Vec<HistoryEntry> {
+    let Some(dir) = history_dir() else { return Vec::new() };
+    let Ok(read_dir) = std::fs::read_dir(&dir) else { return Vec::new() };
+
+    let mut entries: Vec<HistoryEntry> = read_dir
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.path().extension().and_then(|s| s.to_str()) == Some("json"))
+        .filter_map(|entry| {
+            let path = entry.path();
+            let session = Session::load_json(&path).ok()?;
+            let discipline = path
+                .file_stem()?
+                .to_string_lossy()
+                .split('-')
+                .next()
+                .unwrap_or("unknown")
+                .to_string();
+            Some(HistoryEntry {
+                started_at_secs: session
+                    .started_at
+                    .duration_since(UNIX_EPOCH)
+                    .unwrap_or_default()
+                    .as_secs(),
+                shooter: session.shooter.name.clone(),
+                club: session.shooter.club.clone(),
+                total: session.total(),
+                x_count: session.x_count(),
+                discipline,
+                path,
+            })
+        })
+        .collect();
+    entries.sort_by(|a, b| b.started_at_secs.cmp(&a.started_at_secs));
+    entries
+}
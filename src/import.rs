@@ -0,0 +1,187 @@
+//! Importers for shot data exported from commercial electronic target
+//! systems, so sessions shot on borrowed hardware still show up in this
+//! app's history and stats. Each format is read back into a
+//! single-series `Session` that the caller merges into their own.
+
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::time::SystemTime;
+
+use crate::session::{Series, Session, Shooter, Shot};
+
+/// Parses a SIUS ASCII exchange file (the format written by
+/// `export::interop::write_sius_ascii`): `shot_no;value;x_mm;y_mm;x_flag`.
+pub fn read_sius_ascii(path: &Path) -> io::Result<Session> {
+    let text = fs::read_to_string(path)?;
+    let mut shooter_name = String::new();
+    let mut shots = Vec::new();
+
+    for line in text.lines() {
+        if let Some(rest) = line.strip_prefix("SHOOTER;") {
+            shooter_name = rest.to_string();
+            continue;
+        }
+        let fields: Vec<&str> = line.split(';').collect();
+        let [num, value, x_mm, y_mm, is_x] = fields[..] else { continue };
+        let (Ok(number), Ok(value), Ok(x_mm), Ok(y_mm)) =
+            (num.parse(), value.parse(), x_mm.parse(), y_mm.parse())
+        else {
+            continue;
+        };
+        shots.push(shot(number, value, x_mm, y_mm, is_x.trim() == "1"));
+    }
+    Ok(session_from_shots(shooter_name, "Imported (SIUS)", shots))
+}
+
+/// Parses a Meyton exchange file (the format written by
+/// `export::interop::write_meyton`): whitespace-separated columns per
+/// shot line, `SERIES`/`TOTAL` lines ignored.
+pub fn read_meyton(path: &Path) -> io::Result<Session> {
+    let text = fs::read_to_string(path)?;
+    let mut shooter_name = String::new();
+    let mut shots = Vec::new();
+
+    for line in text.lines() {
+        if let Some(rest) = line.strip_prefix("SHOOTER=") {
+            shooter_name = rest.to_string();
+            continue;
+        }
+        let fields: Vec<&str> = line.trim().split_whitespace().collect();
+        let (Some(num), Some(value), Some(x_mm), Some(y_mm)) =
+            (fields.first(), fields.get(1), fields.get(2), fields.get(3))
+        else {
+            continue;
+        };
+        let (Ok(number), Ok(value), Ok(x_mm), Ok(y_mm)) =
+            (num.parse(), value.parse(), x_mm.parse(), y_mm.parse())
+        else {
+            continue;
+        };
+        let is_x = fields.get(4) == Some(&"X");
+        shots.push(shot(number, value, x_mm, y_mm, is_x));
+    }
+    Ok(session_from_shots(shooter_name, "Imported (Meyton)", shots))
+}
+
+/// Parses a Megalink-style CSV export: header `number,value,x_mm,y_mm,is_x`
+/// followed by one row per shot.
+pub fn read_megalink_csv(path: &Path) -> io::Result<Session> {
+    let text = fs::read_to_string(path)?;
+    let mut shots = Vec::new();
+
+    for line in text.lines().skip(1) {
+        let fields: Vec<&str> = line.split(',').collect();
+        let [num, value, x_mm, y_mm, is_x] = fields[..] else { continue };
+        let (Ok(number), Ok(value), Ok(x_mm), Ok(y_mm)) =
+            (num.parse(), value.parse(), x_mm.parse(), y_mm.parse())
+        else {
+            continue;
+        };
+        shots.push(shot(number, value, x_mm, y_mm, is_x.trim() == "1" || is_x.trim() == "true"));
+    }
+    Ok(session_from_shots(String::new(), "Imported (Megalink)", shots))
+}
+
+fn shot(number: usize, value: f32, x_mm: f32, y_mm: f32, is_x: bool) -> Shot {
+    Shot {
+        number,
+        x_mm,
+        y_mm,
+        value,
+        is_x,
+        timestamp: SystemTime::now(),
+        note: None,
+        flagged: false,
+        manual: true,
+        timer_split_secs: None,
+        acoustic_confirmed: false,
+    }
+}
+
+fn session_from_shots(shooter_name: String, series_label: &str, shots: Vec<Shot>) -> Session {
+    let mut session = Session::new(Shooter { name: shooter_name, club: String::new() });
+    session.series.push(Series { label: series_label.to_string(), shots });
+    session
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::export::interop::{write_meyton, write_sius_ascii};
+
+    fn scratch_path(name: &str) -> std::path::PathBuf {
+        std::env::temp_dir().join(format!("precision-scorer-test-{}-{name}", std::process::id()))
+    }
+
+    fn source_session() -> Session {
+        let mut session = Session::new(Shooter { name: "Jane Doe".to_string(), club: String::new() });
+        session.series.push(Series {
+            label: "Series 1".to_string(),
+            shots: vec![
+                shot(1, 10.9, 0.1, -0.2, true),
+                shot(2, 9.5, -1.0, 1.0, false),
+            ],
+        });
+        session
+    }
+
+    #[test]
+    fn sius_ascii_round_trips_shot_values() {
+        let path = scratch_path("sius-roundtrip.txt");
+        write_sius_ascii(&source_session(), &crate::target::issf_10m(), &path).unwrap();
+        let imported = read_sius_ascii(&path).unwrap();
+        let _ = fs::remove_file(&path);
+
+        assert_eq!(imported.shooter.name, "Jane Doe");
+        let shots: Vec<_> = imported.all_shots().collect();
+        assert_eq!(shots.len(), 2);
+        assert_eq!(shots[0].number, 1);
+        assert_eq!(shots[0].value, 10.9);
+        assert_eq!(shots[0].x_mm, 0.1);
+        assert_eq!(shots[0].y_mm, -0.2);
+        assert!(shots[0].is_x);
+        assert!(!shots[1].is_x);
+    }
+
+    #[test]
+    fn meyton_round_trips_shot_values() {
+        let path = scratch_path("meyton-roundtrip.txt");
+        write_meyton(&source_session(), &crate::target::issf_10m(), &path).unwrap();
+        let imported = read_meyton(&path).unwrap();
+        let _ = fs::remove_file(&path);
+
+        assert_eq!(imported.shooter.name, "Jane Doe");
+        let shots: Vec<_> = imported.all_shots().collect();
+        assert_eq!(shots.len(), 2);
+        assert_eq!(shots[0].value, 10.9);
+        assert!(shots[0].is_x);
+        assert!(!shots[1].is_x);
+    }
+
+    #[test]
+    fn megalink_csv_parses_header_and_rows() {
+        let path = scratch_path("megalink.csv");
+        fs::write(&path, "number,value,x_mm,y_mm,is_x\n1,10.9,0.1,-0.2,true\n2,9.5,-1.0,1.0,false\n").unwrap();
+        let imported = read_megalink_csv(&path).unwrap();
+        let _ = fs::remove_file(&path);
+
+        let shots: Vec<_> = imported.all_shots().collect();
+        assert_eq!(shots.len(), 2);
+        assert_eq!(shots[0].value, 10.9);
+        assert!(shots[0].is_x);
+        assert!(!shots[1].is_x);
+    }
+
+    #[test]
+    fn malformed_lines_are_skipped_rather_than_erroring() {
+        let path = scratch_path("sius-malformed.txt");
+        fs::write(&path, "SIUS-ASCII;1.0;ISSF 10m\nSHOOTER;Jane Doe\nnot;a;valid;shot;line;extra\n1;10.9;0.10;-0.20;1\n").unwrap();
+        let imported = read_sius_ascii(&path).unwrap();
+        let _ = fs::remove_file(&path);
+
+        let shots: Vec<_> = imported.all_shots().collect();
+        assert_eq!(shots.len(), 1);
+        assert_eq!(shots[0].number, 1);
+    }
+}
@@ -0,0 +1,55 @@
+//! Owns the single tokio runtime shared by network-facing integrations,
+//! so new async features don't each spin up their own thread pool.
+//!
+//! The existing network services (`api`, `ws`, `mqtt`, `mjpeg`,
+//! `remote_camera`, `acoustic`, `shot_timer`, `stream_overlay`) are
+//! blocking-socket loops built on `std::thread::spawn`, not `Future`s;
+//! porting each one to run as a task on this runtime is real, separate
+//! work per service rather than something to fold into landing the
+//! runtime itself. `webhook` posting moves onto it here as the first
+//! tenant, since it was already a fire-and-forget background call with
+//! no shared state to race.
+
+use std::sync::Mutex;
+use std::time::Duration;
+
+use tokio::runtime::Runtime;
+
+use crate::webhook::WebhookConfig;
+
+static RUNTIME: Mutex<Option<Runtime>> = Mutex::new(None);
+
+/// Runs `f` against the shared runtime, building it on first use.
+fn with_runtime<R>(f: impl FnOnce(&Runtime) -> R) -> R {
+    let mut guard = RUNTIME.lock().unwrap();
+    let runtime = guard.get_or_insert_with(|| {
+        Runtime::new().expect("failed to start the shared integrations runtime")
+    });
+    f(runtime)
+}
+
+/// Posts a milestone webhook on the shared runtime instead of spawning a
+/// dedicated `std::thread`; fire-and-forget, same as before.
+pub fn post_webhook_milestone(config: WebhookConfig, message: String, image_png: Vec<u8>) {
+    with_runtime(|runtime| {
+        runtime.spawn(async move {
+            let result = tokio::task::spawn_blocking(move || {
+                crate::webhook::post_milestone(&config, &message, &image_png)
+            })
+            .await;
+            match result {
+                Ok(Err(e)) => tracing::warn!(error = %e, "milestone webhook failed"),
+                Err(e) => tracing::warn!(error = %e, "milestone webhook task panicked"),
+                Ok(Ok(())) => {}
+            }
+        });
+    });
+}
+
+/// Waits (briefly) for in-flight integration work to finish, then tears
+/// down the runtime; called once from `MyApp::on_exit`.
+pub fn shutdown() {
+    if let Some(runtime) = RUNTIME.lock().unwrap().take() {
+        runtime.shutdown_timeout(Duration::from_secs(2));
+    }
+}
@@ -0,0 +1,108 @@
+//! Multi-lane support: each [`Lane`] bundles the calibration, processor
+//! settings, target and session that used to be single global fields on
+//! `MyApp`, so a club can record more than one shooter position from the
+//! same instance.
+//!
+//! **This is explicitly a precursor, not the full "N independent lanes
+//! running concurrently" feature.** Only one lane is "live" (has the
+//! app's camera feed and frame pipeline attached) at a time; switching
+//! swaps its state into the fields the rest of the app already reads —
+//! see `MyApp::save_active_lane`/`load_active_lane`. Running every
+//! lane's camera and [`crate::pipeline::FramePipeline`] concurrently is
+//! blocked on [`crate::camera::Camera`] actually having a device backend
+//! (right now `Camera::read_frame` is a stub that always errors on every
+//! platform, so there's no second camera to run concurrently yet); that
+//! backend work plus a `FramePipeline` per lane is real, separate,
+//! larger follow-up work and isn't attempted here. [`LaneManager::show_tiled_overview`]
+//! gives a real tiled summary of every lane's scoring state today —
+//! camera thumbnails will slot into the same grid once per-lane capture
+//! exists.
+
+use crate::calibration::ScoringConfig;
+use crate::processor::ProcessorSettings;
+use crate::session::{Session, Shooter};
+use crate::target::TargetType;
+
+pub struct Lane {
+    pub name: String,
+    pub calibration: ScoringConfig,
+    pub processor_settings: ProcessorSettings,
+    pub target: TargetType,
+    pub session: Session,
+}
+
+impl Lane {
+    pub fn new(name: String, target: TargetType) -> Self {
+        Self {
+            name,
+            calibration: ScoringConfig::default(),
+            processor_settings: ProcessorSettings::default(),
+            target,
+            session: Session::new(Shooter::default()),
+        }
+    }
+}
+
+pub struct LaneManager {
+    pub lanes: Vec<Lane>,
+    pub active: usize,
+}
+
+impl LaneManager {
+    pub fn new(first: Lane) -> Self {
+        Self { lanes: vec![first], active: 0 }
+    }
+
+    pub fn add_lane(&mut self, lane: Lane) {
+        self.lanes.push(lane);
+        self.active = self.lanes.len() - 1;
+    }
+
+    /// Draws the lane switcher strip; returns the clicked lane's index
+    /// when it differs from `active` (the caller is responsible for
+    /// saving the outgoing lane's state before applying the new one).
+    pub fn show_switcher(&mut self, ui: &mut egui::Ui) -> Option<usize> {
+        let mut clicked = None;
+        ui.horizontal(|ui| {
+            ui.label("Lanes:");
+            for i in 0..self.lanes.len() {
+                if ui.selectable_label(self.active == i, &self.lanes[i].name).clicked() && i != self.active {
+                    clicked = Some(i);
+                }
+            }
+        });
+        clicked
+    }
+
+    /// Draws a tiled overview showing every lane's current score at a
+    /// glance instead of one name at a time, same return contract as
+    /// [`Self::show_switcher`]. `active_shots`/`active_total` are the
+    /// live, not-yet-saved-into-`Lane` state for whichever lane is
+    /// currently active, so its tile doesn't show stale numbers from the
+    /// last time it was switched away from.
+    pub fn show_tiled_overview(&mut self, ui: &mut egui::Ui, active_total: f32, active_x_count: usize) -> Option<usize> {
+        let mut clicked = None;
+        egui::Grid::new("lane_tiled_overview").num_columns(4).striped(true).show(ui, |ui| {
+            ui.strong("Lane");
+            ui.strong("Total");
+            ui.strong("X count");
+            ui.strong("");
+            ui.end_row();
+            for i in 0..self.lanes.len() {
+                let (total, x_count) = if i == self.active {
+                    (active_total, active_x_count)
+                } else {
+                    (self.lanes[i].session.total(), self.lanes[i].session.x_count())
+                };
+                ui.label(&self.lanes[i].name);
+                ui.label(format!("{total:.1}"));
+                ui.label(x_count.to_string());
+                if ui.selectable_label(self.active == i, "Switch").clicked() && i != self.active {
+                    clicked = Some(i);
+                }
+                ui.end_row();
+            }
+        });
+        clicked
+    }
+}
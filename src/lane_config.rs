@@ -0,0 +1,75 @@
+//! Lane-wide operator config, loaded from a TOML file and hot-reloaded
+//! by polling its mtime, so a range officer can tweak a lane's detection
+//! thresholds, target choice, camera preferences and output toggles from
+//! a text editor without restarting mid-match.
+
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+use serde::Deserialize;
+
+#[derive(Debug, Clone, Deserialize, Default)]
+pub struct LaneConfig {
+    #[serde(default)]
+    pub processor: ProcessorToml,
+    /// Built-in target preset name; see `crate::target::presets`.
+    pub target_preset: Option<String>,
+    #[serde(default)]
+    pub camera: CameraToml,
+    #[serde(default)]
+    pub outputs: OutputsToml,
+}
+
+#[derive(Debug, Clone, Deserialize, Default)]
+pub struct ProcessorToml {
+    pub threshold: Option<u8>,
+    pub min_contour_area: Option<f32>,
+    pub max_contour_area: Option<f32>,
+}
+
+#[derive(Debug, Clone, Deserialize, Default)]
+pub struct CameraToml {
+    pub resolution: Option<(u32, u32)>,
+}
+
+#[derive(Debug, Clone, Deserialize, Default)]
+pub struct OutputsToml {
+    pub mqtt_broker: Option<String>,
+    pub api_addr: Option<String>,
+}
+
+pub fn load(path: &Path) -> io::Result<LaneConfig> {
+    let text = std::fs::read_to_string(path)?;
+    toml::from_str(&text).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e.to_string()))
+}
+
+/// Polls a config file's mtime once per call; re-parses and returns the
+/// new config only when the file has actually changed since the last
+/// poll, so callers can check every frame for free.
+pub struct HotReloadWatcher {
+    path: PathBuf,
+    last_modified: Option<SystemTime>,
+}
+
+impl HotReloadWatcher {
+    pub fn new(path: PathBuf) -> Self {
+        Self { path, last_modified: None }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// `Some` when the file's mtime advanced since the last poll
+    /// (including the very first poll, so the config is applied once on
+    /// startup); `None` otherwise.
+    pub fn poll(&mut self) -> Option<io::Result<LaneConfig>> {
+        let modified = std::fs::metadata(&self.path).and_then(|m| m.modified()).ok()?;
+        if self.last_modified == Some(modified) {
+            return None;
+        }
+        self.last_modified = Some(modified);
+        Some(load(&self.path))
+    }
+}
@@ -0,0 +1,60 @@
+//! Drives an RS-485 LED scoreboard over serial: a small templated ASCII
+//! frame, addressed for multi-drop wiring, sent after every shot with the
+//! running total and last shot value.
+
+use std::io::{self, Write};
+use std::time::Duration;
+
+use serialport::SerialPort;
+
+#[derive(Debug, Clone)]
+pub struct LedScoreboardConfig {
+    pub port_name: String,
+    pub baud_rate: u32,
+    /// Multi-drop address on the RS-485 bus.
+    pub address: u8,
+    /// `{addr}`, `{total}` and `{last}` are substituted before sending.
+    pub template: String,
+}
+
+impl Default for LedScoreboardConfig {
+    fn default() -> Self {
+        Self {
+            port_name: String::new(),
+            baud_rate: 9600,
+            address: 1,
+            template: "ADDR{addr} TOTAL:{total} LAST:{last}\r\n".to_string(),
+        }
+    }
+}
+
+pub struct LedScoreboardOutput {
+    port: Box<dyn SerialPort>,
+    config: LedScoreboardConfig,
+}
+
+impl LedScoreboardOutput {
+    pub fn connect(config: LedScoreboardConfig) -> io::Result<Self> {
+        let port = serialport::new(&config.port_name, config.baud_rate)
+            .timeout(Duration::from_millis(500))
+            .open()
+            .map_err(|e| io::Error::new(io::ErrorKind::Other, e.to_string()))?;
+        Ok(Self { port, config })
+    }
+
+    pub fn port_name(&self) -> &str {
+        &self.config.port_name
+    }
+
+    /// Renders the template with the current score and writes it to the
+    /// bus; `last_value` is blank when the session has no shots yet.
+    pub fn update(&mut self, total: f32, last_value: Option<f32>) -> io::Result<()> {
+        let frame = self
+            .config
+            .template
+            .replace("{addr}", &self.config.address.to_string())
+            .replace("{total}", &format!("{total:.1}"))
+            .replace("{last}", &last_value.map(|v| format!("{v:.1}")).unwrap_or_default());
+        self.port.write_all(frame.as_bytes())
+    }
+}
@@ -0,0 +1,126 @@
+mod acoustic;
+mod api;
+mod app;
+mod calibration;
+mod camera;
+mod core;
+mod csv_feed;
+mod error;
+mod events;
+mod export;
+mod headless;
+mod history;
+mod import;
+mod integrations;
+mod lane;
+mod lane_config;
+mod led_scoreboard;
+mod mjpeg;
+mod mqtt;
+mod notify;
+mod overlay;
+mod pipeline;
+mod processor;
+mod project;
+mod qr;
+mod recovery;
+mod remote_camera;
+mod replay;
+mod scripting;
+mod session;
+mod settings;
+mod shot_timer;
+mod shot_trigger;
+mod stream_overlay;
+mod sync;
+mod target;
+mod telemetry;
+mod timer;
+mod udp;
+mod ui;
+mod undo;
+mod units;
+mod video_batch;
+mod web;
+mod webhook;
+mod ws;
+
+/// Shared shape for `--input <path> --config <path> --output <path>`
+/// batch subcommands (`--headless`, `--score-video`); native-only since
+/// wasm32 has neither CLI args nor a filesystem to batch over.
+#[cfg(not(target_arch = "wasm32"))]
+struct BatchArgs {
+    input: std::path::PathBuf,
+    config: std::path::PathBuf,
+    output: std::path::PathBuf,
+}
+
+/// Parses `<flag> --input <path> --config <path> --output <path>` from
+/// the process arguments; returns `None` if `flag` wasn't given.
+#[cfg(not(target_arch = "wasm32"))]
+fn parse_batch_args(flag: &str) -> Option<Result<BatchArgs, String>> {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if !args.iter().any(|a| a == flag) {
+        return None;
+    }
+    let mut input = None;
+    let mut config = None;
+    let mut output = None;
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--input" => input = iter.next().cloned(),
+            "--config" => config = iter.next().cloned(),
+            "--output" => output = iter.next().cloned(),
+            _ => {}
+        }
+    }
+    Some((|| {
+        Ok(BatchArgs {
+            input: input.ok_or(format!("{flag} requires --input <path>"))?.into(),
+            config: config.ok_or(format!("{flag} requires --config <file>"))?.into(),
+            output: output.ok_or(format!("{flag} requires --output <path>"))?.into(),
+        })
+    })())
+}
+
+/// Native desktop entry point; see [`web::start_web`] for the wasm32
+/// browser entry point.
+#[cfg(not(target_arch = "wasm32"))]
+fn main() -> eframe::Result<()> {
+    telemetry::init();
+
+    if let Some(parsed) = parse_batch_args("--headless") {
+        run_batch_or_exit("--headless", parsed, |args| {
+            headless::run(&args.input, &args.config, &args.output).map_err(|e| e.to_string())
+        });
+        return Ok(());
+    }
+    if let Some(parsed) = parse_batch_args("--score-video") {
+        run_batch_or_exit("--score-video", parsed, |args| {
+            video_batch::run(&args.input, &args.config, &args.output)
+        });
+        return Ok(());
+    }
+
+    eframe::run_native(
+        "precision-scorer",
+        eframe::NativeOptions::default(),
+        Box::new(|_cc| Ok(Box::<app::MyApp>::default())),
+    )
+}
+
+/// Runs a batch subcommand's parsed args through `body`, printing an
+/// error and exiting nonzero on failure.
+#[cfg(not(target_arch = "wasm32"))]
+fn run_batch_or_exit(
+    flag: &str,
+    parsed: Result<BatchArgs, String>,
+    body: impl FnOnce(&BatchArgs) -> Result<(), String>,
+) {
+    let result = parsed.and_then(|args| body(&args));
+    if let Err(e) = result {
+        tracing::error!(%flag, error = %e, "batch subcommand failed");
+        std::process::exit(1);
+    }
+}
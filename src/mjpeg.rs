@@ -0,0 +1,109 @@
+//! Serves the annotated live frame as an MJPEG stream (`multipart/x-mixed-
+//! replace`), so any browser, OBS browser source, or VLC network stream
+//! can show the live target without running the app itself.
+
+use std::io::{self, Read};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+use image::codecs::jpeg::JpegEncoder;
+use image::RgbImage;
+use tiny_http::{Header, Response, Server, StatusCode};
+
+const BOUNDARY: &str = "frame";
+
+pub struct MjpegServer {
+    clients: Arc<Mutex<Vec<Sender<Vec<u8>>>>>,
+    addr: String,
+}
+
+impl MjpegServer {
+    pub fn start(addr: &str) -> io::Result<Self> {
+        let server = Server::http(addr)
+            .map_err(|e| io::Error::new(io::ErrorKind::AddrInUse, e.to_string()))?;
+        let clients: Arc<Mutex<Vec<Sender<Vec<u8>>>>> = Arc::new(Mutex::new(Vec::new()));
+        let accept_clients = Arc::clone(&clients);
+        thread::spawn(move || accept_loop(server, accept_clients));
+        Ok(Self { clients, addr: addr.to_string() })
+    }
+
+    pub fn addr(&self) -> &str {
+        &self.addr
+    }
+
+    /// JPEG-encodes `frame` and pushes it to every connected client.
+    /// Called once per UI frame; connections whose reader has gone away
+    /// are dropped on the next call.
+    pub fn publish_frame(&self, frame: &RgbImage) {
+        let Ok(mut clients) = self.clients.lock() else { return };
+        if clients.is_empty() {
+            return;
+        }
+        let mut jpeg = Vec::new();
+        if JpegEncoder::new_with_quality(&mut jpeg, 80)
+            .encode(frame.as_raw(), frame.width(), frame.height(), image::ColorType::Rgb8)
+            .is_err()
+        {
+            return;
+        }
+        let mut chunk = Vec::with_capacity(jpeg.len() + 128);
+        chunk.extend_from_slice(format!("--{BOUNDARY}\r\n").as_bytes());
+        chunk.extend_from_slice(b"Content-Type: image/jpeg\r\n");
+        chunk.extend_from_slice(format!("Content-Length: {}\r\n\r\n", jpeg.len()).as_bytes());
+        chunk.extend_from_slice(&jpeg);
+        chunk.extend_from_slice(b"\r\n");
+        clients.retain(|tx| tx.send(chunk.clone()).is_ok());
+    }
+}
+
+fn accept_loop(server: Server, clients: Arc<Mutex<Vec<Sender<Vec<u8>>>>>) {
+    for request in server.incoming_requests() {
+        if request.url() != "/" && request.url() != "/stream" {
+            let _ = request.respond(Response::from_string("not found").with_status_code(404));
+            continue;
+        }
+        let (tx, rx) = mpsc::channel();
+        if let Ok(mut clients) = clients.lock() {
+            clients.push(tx);
+        }
+        let content_type = Header::from_bytes(
+            &b"Content-Type"[..],
+            format!("multipart/x-mixed-replace; boundary={BOUNDARY}").as_bytes(),
+        )
+        .expect("valid header");
+        let reader = StreamReader { rx, pending: Vec::new(), pending_pos: 0 };
+        let response = Response::new(StatusCode(200), vec![content_type], reader, None, None);
+        thread::spawn(move || {
+            let _ = request.respond(response);
+        });
+    }
+}
+
+/// Adapts the per-connection channel of encoded frame chunks to a
+/// blocking `Read`, which is what `tiny_http` needs to stream a response
+/// of unknown total length. Keeps whatever didn't fit in the caller's
+/// buffer so a chunk larger than one `read()` call is never truncated.
+struct StreamReader {
+    rx: Receiver<Vec<u8>>,
+    pending: Vec<u8>,
+    pending_pos: usize,
+}
+
+impl Read for StreamReader {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if self.pending_pos >= self.pending.len() {
+            match self.rx.recv() {
+                Ok(chunk) => {
+                    self.pending = chunk;
+                    self.pending_pos = 0;
+                }
+                Err(_) => return Ok(0),
+            }
+        }
+        let n = (self.pending.len() - self.pending_pos).min(buf.len());
+        buf[..n].copy_from_slice(&self.pending[self.pending_pos..self.pending_pos + n]);
+        self.pending_pos += n;
+        Ok(n)
+    }
+}
@@ -0,0 +1,80 @@
+//! Publishes shot events and session summaries to MQTT, so a smart-range
+//! setup (lights, displays, automation) can react live instead of
+//! polling the REST API.
+
+use rumqttc::{Client, MqttOptions, QoS};
+use serde::{Deserialize, Serialize};
+
+use crate::session::{Session, Shot};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MqttConfig {
+    pub broker_host: String,
+    pub broker_port: u16,
+    pub username: String,
+    pub password: String,
+    pub topic_prefix: String,
+}
+
+impl Default for MqttConfig {
+    fn default() -> Self {
+        Self {
+            broker_host: "localhost".to_string(),
+            broker_port: 1883,
+            username: String::new(),
+            password: String::new(),
+            topic_prefix: "precision-scorer".to_string(),
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct SessionSummary<'a> {
+    shooter: &'a str,
+    total: f32,
+    x_count: usize,
+}
+
+pub struct MqttPublisher {
+    client: Client,
+    topic_prefix: String,
+}
+
+impl MqttPublisher {
+    /// Connects to the broker and starts a background thread driving the
+    /// event loop; publish calls are fire-and-forget from the caller's
+    /// point of view, matching how the WebSocket/API servers push state.
+    pub fn connect(config: &MqttConfig) -> Self {
+        let mut options =
+            MqttOptions::new("precision-scorer", config.broker_host.clone(), config.broker_port);
+        if !config.username.is_empty() {
+            options.set_credentials(config.username.clone(), config.password.clone());
+        }
+        let (client, mut event_loop) = Client::new(options, 10);
+        std::thread::spawn(move || loop {
+            if event_loop.poll().is_err() {
+                break;
+            }
+        });
+        Self { client, topic_prefix: config.topic_prefix.clone() }
+    }
+
+    pub fn publish_shot(&self, shot: &Shot) {
+        let topic = format!("{}/shot", self.topic_prefix);
+        if let Ok(json) = serde_json::to_string(shot) {
+            let _ = self.client.publish(topic, QoS::AtLeastOnce, false, json);
+        }
+    }
+
+    pub fn publish_session_summary(&self, session: &Session) {
+        let topic = format!("{}/session", self.topic_prefix);
+        let summary = SessionSummary {
+            shooter: &session.shooter.name,
+            total: session.total(),
+            x_count: session.x_count(),
+        };
+        if let Ok(json) = serde_json::to_string(&summary) {
+            let _ = self.client.publish(topic, QoS::AtLeastOnce, false, json);
+        }
+    }
+}
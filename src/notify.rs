@@ -0,0 +1,97 @@
+//! Toast notification queue shared across modules, so failures (camera
+//! errors, load failures, detection storms) surface in the UI instead of
+//! silently going to stderr.
+
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+
+const TOAST_DURATION: Duration = Duration::from_secs(5);
+const HISTORY_LIMIT: usize = 200;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+    Info,
+    Warning,
+    Error,
+}
+
+impl Severity {
+    fn color(self) -> egui::Color32 {
+        match self {
+            Severity::Info => egui::Color32::from_rgb(90, 170, 255),
+            Severity::Warning => egui::Color32::from_rgb(230, 180, 0),
+            Severity::Error => egui::Color32::from_rgb(220, 60, 60),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Notification {
+    pub severity: Severity,
+    pub message: String,
+    pub shown_at: Instant,
+}
+
+/// Central place every module pushes user-visible events to, instead of
+/// writing to stderr or a single `last_error` string. Keeps a bounded
+/// history alongside the transient toasts drawn each frame.
+pub struct NotificationCenter {
+    active: VecDeque<Notification>,
+    history: VecDeque<Notification>,
+}
+
+impl Default for NotificationCenter {
+    fn default() -> Self {
+        Self { active: VecDeque::new(), history: VecDeque::new() }
+    }
+}
+
+impl NotificationCenter {
+    pub fn push(&mut self, severity: Severity, message: impl Into<String>) {
+        let note = Notification { severity, message: message.into(), shown_at: Instant::now() };
+        self.active.push_back(note.clone());
+        self.history.push_back(note);
+        if self.history.len() > HISTORY_LIMIT {
+            self.history.pop_front();
+        }
+    }
+
+    pub fn info(&mut self, message: impl Into<String>) {
+        self.push(Severity::Info, message);
+    }
+
+    pub fn warning(&mut self, message: impl Into<String>) {
+        self.push(Severity::Warning, message);
+    }
+
+    pub fn error(&mut self, message: impl Into<String>) {
+        self.push(Severity::Error, message);
+    }
+
+    pub fn history(&self) -> impl Iterator<Item = &Notification> {
+        self.history.iter().rev()
+    }
+
+    /// Draws the stack of still-fresh toasts in the top-right corner and
+    /// drops ones that have expired. Call once per frame.
+    pub fn show_toasts(&mut self, ctx: &egui::Context) {
+        self.active.retain(|n| n.shown_at.elapsed() < TOAST_DURATION);
+        if !self.active.is_empty() {
+            ctx.request_repaint_after(Duration::from_millis(200));
+        }
+
+        egui::Area::new(egui::Id::new("toast_area"))
+            .anchor(egui::Align2::RIGHT_TOP, egui::vec2(-12.0, 12.0))
+            .show(ctx, |ui| {
+                for note in &self.active {
+                    egui::Frame::popup(ui.style()).show(ui, |ui| {
+                        ui.horizontal(|ui| {
+                            ui.colored_label(note.severity.color(), "●");
+                            ui.label(&note.message);
+                        });
+                    });
+                    ui.add_space(4.0);
+                }
+            });
+    }
+}
@@ -0,0 +1,90 @@
+//! Shared overlay styling: how rings, holes and the center marker are
+//! drawn, both on-screen (egui painter) and burned into exported images.
+
+use serde::{Deserialize, Serialize};
+
+use crate::target::TargetType;
+
+/// Rotates a point (mm, relative to center) by `angle_deg` clockwise, so
+/// the overlay's "up" can be aligned with a target print that came out
+/// slightly rotated relative to the camera.
+pub fn rotate_mm(x_mm: f32, y_mm: f32, angle_deg: f32) -> (f32, f32) {
+    let theta = angle_deg.to_radians();
+    let (sin, cos) = theta.sin_cos();
+    (x_mm * cos - y_mm * sin, x_mm * sin + y_mm * cos)
+}
+
+/// Score-zone color palette, configurable so it can be adapted to target
+/// prints where the default colors don't stand out.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ZonePalette {
+    /// Color for the 10/9 rings.
+    pub high: [u8; 3],
+    /// Color for the 8/7 rings.
+    pub mid: [u8; 3],
+    /// Color for everything below 7.
+    pub low: [u8; 3],
+}
+
+impl Default for ZonePalette {
+    fn default() -> Self {
+        Self {
+            high: [0, 200, 0],
+            mid: [230, 200, 0],
+            low: [220, 0, 0],
+        }
+    }
+}
+
+impl ZonePalette {
+    /// Picks the color for a given ring value (10 down to 1).
+    pub fn color_for_ring(&self, ring_value: f32) -> [u8; 3] {
+        if ring_value >= 9.0 {
+            self.high
+        } else if ring_value >= 7.0 {
+            self.mid
+        } else {
+            self.low
+        }
+    }
+}
+
+/// Full appearance settings for on-screen and exported overlays, so
+/// prints where the default colors are invisible can be worked around.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OverlaySettings {
+    pub zone_palette: ZonePalette,
+    pub hole_opacity: f32,
+    pub hole_line_width: f32,
+    pub ring_line_width: f32,
+    pub center_color: [u8; 3],
+    pub label_color: [u8; 3],
+}
+
+impl Default for OverlaySettings {
+    fn default() -> Self {
+        Self {
+            zone_palette: ZonePalette::default(),
+            hole_opacity: 1.0,
+            hole_line_width: 2.0,
+            ring_line_width: 1.5,
+            center_color: [255, 255, 0],
+            label_color: [255, 255, 255],
+        }
+    }
+}
+
+/// The ring value each entry in `TargetType::ring_radii_mm` represents,
+/// paired with its zone color for drawing.
+pub fn ring_colors(target: &TargetType, palette: &ZonePalette) -> Vec<([u8; 3], f32)> {
+    let n = target.ring_radii_mm.len();
+    target
+        .ring_radii_mm
+        .iter()
+        .enumerate()
+        .map(|(i, radius)| {
+            let ring_value = (n - i) as f32;
+            (palette.color_for_ring(ring_value), *radius)
+        })
+        .collect()
+}
@@ -0,0 +1,236 @@
+//! Frame pipeline: capture → preprocess → detect → score → render as
+//! explicit stages, each on its own thread and connected by bounded,
+//! drop-oldest queues. A slow downstream stage (a heavy ONNX model, an
+//! expensive render) can never back up memory or add multi-second lag to
+//! what's on screen — it just skips ahead to the newest frame instead of
+//! blocking the producer or growing without bound.
+//!
+//! Scoring here only turns a [`Detection`] into millimeter coordinates
+//! and a ring value — it doesn't touch [`crate::session::Session`] (shot
+//! numbering, undo history) since that's inherently single-threaded UI
+//! state. The full session-aware render still happens on the UI thread
+//! via [`crate::export::image::render_annotated`]; this pipeline's render
+//! stage only draws lightweight crosshairs over its own detections, for
+//! a live preview that doesn't wait on the UI thread's session lock.
+
+use std::collections::VecDeque;
+use std::sync::{Arc, Condvar, Mutex};
+
+use image::{Rgb, RgbImage};
+
+use crate::calibration::ScoringConfig;
+use crate::processor::{Detection, Processor, ProcessorSettings};
+use crate::target::TargetType;
+
+/// How many items each inter-stage queue holds before it starts dropping
+/// the oldest to make room for the newest.
+const STAGE_QUEUE_CAPACITY: usize = 2;
+
+/// A bounded queue that drops the oldest entry instead of blocking the
+/// producer once full, and only ever hands the *newest* entry to a
+/// consumer — a stale frame is worse than no frame for a live preview.
+struct DropOldestQueue<T> {
+    items: Mutex<VecDeque<T>>,
+    capacity: usize,
+    not_empty: Condvar,
+}
+
+impl<T: Send> DropOldestQueue<T> {
+    fn new(capacity: usize) -> Self {
+        Self { items: Mutex::new(VecDeque::with_capacity(capacity)), capacity, not_empty: Condvar::new() }
+    }
+
+    fn push(&self, item: T) {
+        let mut items = self.items.lock().unwrap();
+        if items.len() == self.capacity {
+            items.pop_front();
+        }
+        items.push_back(item);
+        self.not_empty.notify_one();
+    }
+
+    /// Blocks until at least one item is queued, then returns the newest
+    /// one and discards anything older that piled up in the meantime.
+    fn pop_latest_blocking(&self) -> T {
+        let mut items = self.items.lock().unwrap();
+        while items.is_empty() {
+            items = self.not_empty.wait(items).unwrap();
+        }
+        let latest = items.pop_back().expect("checked non-empty above");
+        items.clear();
+        latest
+    }
+
+    fn try_pop_latest(&self) -> Option<T> {
+        let mut items = self.items.lock().unwrap();
+        let latest = items.pop_back();
+        items.clear();
+        latest
+    }
+}
+
+/// A detection converted into scored, millimeter coordinates.
+#[derive(Debug, Clone, Copy)]
+pub struct ScoredDetection {
+    pub center_px: (f32, f32),
+    pub x_mm: f32,
+    pub y_mm: f32,
+    pub value: f32,
+    pub is_x: bool,
+}
+
+/// Output of the render stage: the original frame with lightweight
+/// crosshair markers drawn over every scored detection, plus the detect
+/// stage's own metrics/error so a caller can mirror them into whatever
+/// status display it already has (e.g. [`crate::ui::status_bar`]).
+pub struct RenderedFrame {
+    pub image: RgbImage,
+    pub detections: Vec<Detection>,
+    pub scored: Vec<ScoredDetection>,
+    pub metrics: crate::processor::ProcessorMetrics,
+    pub error: Option<crate::error::AppError>,
+}
+
+/// Live configuration the detect/score stages re-read on every frame, so
+/// dragging a threshold slider or recalibrating takes effect immediately
+/// instead of requiring the pipeline (and its threads) to be torn down
+/// and rebuilt.
+pub struct PipelineConfig {
+    pub settings: Mutex<ProcessorSettings>,
+    pub calibration: Mutex<ScoringConfig>,
+    pub target: Mutex<TargetType>,
+}
+
+impl PipelineConfig {
+    pub fn new(settings: ProcessorSettings, calibration: ScoringConfig, target: TargetType) -> Arc<Self> {
+        Arc::new(Self {
+            settings: Mutex::new(settings),
+            calibration: Mutex::new(calibration),
+            target: Mutex::new(target),
+        })
+    }
+}
+
+/// Owns the capture-side input queue and render-side output queue of a
+/// running four-stage pipeline; the intermediate queues live only inside
+/// the spawned stage threads.
+pub struct FramePipeline {
+    capture_queue: Arc<DropOldestQueue<RgbImage>>,
+    output_queue: Arc<DropOldestQueue<RenderedFrame>>,
+}
+
+impl FramePipeline {
+    /// Spawns the preprocess/detect/score/render stages, each reading
+    /// live values out of `config` rather than a snapshot.
+    pub fn start(config: Arc<PipelineConfig>) -> Self {
+        let capture_queue = Arc::new(DropOldestQueue::new(STAGE_QUEUE_CAPACITY));
+        let preprocess_queue = Arc::new(DropOldestQueue::new(STAGE_QUEUE_CAPACITY));
+        let detect_queue = Arc::new(DropOldestQueue::new(STAGE_QUEUE_CAPACITY));
+        let score_queue = Arc::new(DropOldestQueue::new(STAGE_QUEUE_CAPACITY));
+        let output_queue = Arc::new(DropOldestQueue::new(STAGE_QUEUE_CAPACITY));
+
+        spawn_preprocess_stage(capture_queue.clone(), preprocess_queue.clone());
+        spawn_detect_stage(preprocess_queue, detect_queue.clone(), config.clone());
+        spawn_score_stage(detect_queue, score_queue.clone(), config);
+        spawn_render_stage(score_queue, output_queue.clone());
+
+        Self { capture_queue, output_queue }
+    }
+
+    /// Feeds a newly captured frame into the pipeline. Never blocks: a
+    /// full queue just drops its oldest frame.
+    pub fn push_frame(&self, frame: RgbImage) {
+        puffin::profile_scope!("capture");
+        self.capture_queue.push(frame);
+    }
+
+    /// Non-blocking: returns the newest fully rendered frame, if any has
+    /// finished the pipeline since the last poll.
+    pub fn try_take_rendered(&self) -> Option<RenderedFrame> {
+        self.output_queue.try_pop_latest()
+    }
+}
+
+fn spawn_preprocess_stage(
+    input: Arc<DropOldestQueue<RgbImage>>,
+    output: Arc<DropOldestQueue<(RgbImage, image::GrayImage)>>,
+) {
+    std::thread::spawn(move || loop {
+        let frame = input.pop_latest_blocking();
+        let gray = image::imageops::grayscale(&frame);
+        output.push((frame, gray));
+    });
+}
+
+/// What the detect stage hands the score stage: the frame, its
+/// detections, and this run's metrics/error for status reporting.
+type Detected = (RgbImage, Vec<Detection>, crate::processor::ProcessorMetrics, Option<crate::error::AppError>);
+/// What the score stage hands the render stage.
+type Scored = (RgbImage, Vec<Detection>, Vec<ScoredDetection>, crate::processor::ProcessorMetrics, Option<crate::error::AppError>);
+
+fn spawn_detect_stage(
+    input: Arc<DropOldestQueue<(RgbImage, image::GrayImage)>>,
+    output: Arc<DropOldestQueue<Detected>>,
+    config: Arc<PipelineConfig>,
+) {
+    std::thread::spawn(move || {
+        // The precomputed grayscale from the preprocess stage isn't
+        // threaded into `Processor::process` yet — each backend still
+        // regrayscales internally — but the queue still bounds memory
+        // and decouples this stage's pace from capture's. Wiring
+        // backends to accept a precomputed `GrayImage` is a follow-up.
+        let mut processor = Processor::default();
+        loop {
+            let (frame, _gray) = input.pop_latest_blocking();
+            processor.settings = config.settings.lock().unwrap().clone();
+            let detections = processor.process(&frame);
+            output.push((frame, detections, processor.last_metrics, processor.last_error.clone()));
+        }
+    });
+}
+
+fn spawn_score_stage(input: Arc<DropOldestQueue<Detected>>, output: Arc<DropOldestQueue<Scored>>, config: Arc<PipelineConfig>) {
+    std::thread::spawn(move || loop {
+        let (frame, detections, metrics, error) = input.pop_latest_blocking();
+        puffin::profile_scope!("score");
+        let calibration = *config.calibration.lock().unwrap();
+        let target = config.target.lock().unwrap().clone();
+        let scored = detections
+            .iter()
+            .map(|detection| {
+                let x_mm = (detection.center_px.0 - calibration.center_px.0) / calibration.pixels_per_mm;
+                let y_mm = (calibration.center_px.1 - detection.center_px.1) / calibration.pixels_per_mm;
+                let distance_mm = (x_mm * x_mm + y_mm * y_mm).sqrt();
+                let (value, is_x) = target.score(distance_mm);
+                ScoredDetection { center_px: detection.center_px, x_mm, y_mm, value, is_x }
+            })
+            .collect();
+        output.push((frame, detections, scored, metrics, error));
+    });
+}
+
+fn spawn_render_stage(input: Arc<DropOldestQueue<Scored>>, output: Arc<DropOldestQueue<RenderedFrame>>) {
+    std::thread::spawn(move || loop {
+        let (mut image, detections, scored, metrics, error) = input.pop_latest_blocking();
+        for point in &scored {
+            draw_crosshair(&mut image, point.center_px, Rgb([255, 0, 0]));
+        }
+        output.push(RenderedFrame { image, detections, scored, metrics, error });
+    });
+}
+
+/// Draws a small red crosshair centered on `center_px`, clipped to the
+/// image bounds — good enough for a live preview; the polished overlay
+/// with rings and labels stays in [`crate::export::image`].
+fn draw_crosshair(image: &mut RgbImage, center_px: (f32, f32), color: Rgb<u8>) {
+    const ARM_LENGTH: i32 = 6;
+    let (width, height) = image.dimensions();
+    let (cx, cy) = (center_px.0 as i32, center_px.1 as i32);
+    for offset in -ARM_LENGTH..=ARM_LENGTH {
+        for (x, y) in [(cx + offset, cy), (cx, cy + offset)] {
+            if x >= 0 && y >= 0 && (x as u32) < width && (y as u32) < height {
+                image.put_pixel(x as u32, y as u32, color);
+            }
+        }
+    }
+}
@@ -0,0 +1,136 @@
+//! Circular Hough transform: votes for candidate hole centers from dark
+//! pixels within a plausible bullet-hole radius range, then keeps local
+//! maxima in the vote accumulator. Better than plain thresholding at
+//! separating overlapping or touching holes.
+
+use image::RgbImage;
+
+use super::{Detection, DetectionBackend, Detections, ProcessorSettings};
+
+/// Radii (px) to vote for; a bullet hole is small, so this stays narrow.
+const MIN_RADIUS_PX: i32 = 2;
+const MAX_RADIUS_PX: i32 = 6;
+const VOTE_STEP_DEGREES: i32 = 30;
+
+pub struct HoughBackend;
+
+impl DetectionBackend for HoughBackend {
+    fn detect(&mut self, frame: &RgbImage, settings: &ProcessorSettings) -> Detections {
+        let gray = image::imageops::grayscale(frame);
+        let (width, height) = gray.dimensions();
+        let mut accumulator = vec![0u32; (width * height) as usize];
+
+        for (x, y, pixel) in gray.enumerate_pixels() {
+            if pixel.0[0] >= settings.threshold {
+                continue;
+            }
+            for radius in MIN_RADIUS_PX..=MAX_RADIUS_PX {
+                let mut angle = 0;
+                while angle < 360 {
+                    let theta = (angle as f32).to_radians();
+                    let cx = x as f32 - radius as f32 * theta.cos();
+                    let cy = y as f32 - radius as f32 * theta.sin();
+                    if cx >= 0.0 && cy >= 0.0 && (cx as u32) < width && (cy as u32) < height {
+                        accumulator[cy as usize * width as usize + cx as usize] += 1;
+                    }
+                    angle += VOTE_STEP_DEGREES;
+                }
+            }
+        }
+
+        let min_votes = (360 / VOTE_STEP_DEGREES) as u32 * (MAX_RADIUS_PX - MIN_RADIUS_PX + 1) as u32 / 2;
+        let mut detections = Vec::new();
+        for y in 0..height {
+            for x in 0..width {
+                let votes = accumulator[(y * width + x) as usize];
+                if votes < min_votes {
+                    continue;
+                }
+                if is_local_maximum(&accumulator, width, height, x, y) {
+                    let area = std::f32::consts::PI * (((MIN_RADIUS_PX + MAX_RADIUS_PX) as f32) / 2.0).powi(2);
+                    detections.push(Detection { center_px: (x as f32, y as f32), area });
+                }
+            }
+        }
+
+        let raw_count = detections.len();
+        let accepted = detections
+            .into_iter()
+            .filter(|d| d.area >= settings.min_contour_area && d.area <= settings.max_contour_area)
+            .collect();
+        Detections { accepted, raw_count, error: None }
+    }
+}
+
+/// True if `(x, y)`'s vote count is the strictest within its 3x3
+/// neighborhood, so nearby votes for the same hole collapse to one peak.
+fn is_local_maximum(accumulator: &[u32], width: u32, height: u32, x: u32, y: u32) -> bool {
+    let votes = accumulator[(y * width + x) as usize];
+    for dy in -1i32..=1 {
+        for dx in -1i32..=1 {
+            if dx == 0 && dy == 0 {
+                continue;
+            }
+            let (nx, ny) = (x as i32 + dx, y as i32 + dy);
+            if nx < 0 || ny < 0 || nx as u32 >= width || ny as u32 >= height {
+                continue;
+            }
+            if accumulator[ny as usize * width as usize + nx as usize] > votes {
+                return false;
+            }
+        }
+    }
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::processor::ProcessorSettings;
+
+    fn settings() -> ProcessorSettings {
+        ProcessorSettings { threshold: 128, min_contour_area: 0.0, max_contour_area: 1000.0, ..ProcessorSettings::default() }
+    }
+
+    #[test]
+    fn all_white_frame_has_no_detections() {
+        let frame = RgbImage::from_pixel(20, 20, image::Rgb([255, 255, 255]));
+        let detections = HoughBackend.detect(&frame, &settings());
+        assert!(detections.accepted.is_empty());
+    }
+
+    #[test]
+    fn dark_disc_produces_a_detection_near_its_center() {
+        let mut frame = RgbImage::from_pixel(20, 20, image::Rgb([255, 255, 255]));
+        let (cx, cy, r) = (10i32, 10i32, 4i32);
+        for y in 0..20i32 {
+            for x in 0..20i32 {
+                if (x - cx).pow(2) + (y - cy).pow(2) <= r * r {
+                    frame.put_pixel(x as u32, y as u32, image::Rgb([0, 0, 0]));
+                }
+            }
+        }
+        let detections = HoughBackend.detect(&frame, &settings());
+        assert!(!detections.accepted.is_empty(), "expected at least one detection for the dark disc");
+        let closest = detections
+            .accepted
+            .iter()
+            .map(|d| ((d.center_px.0 - cx as f32).powi(2) + (d.center_px.1 - cy as f32).powi(2)).sqrt())
+            .fold(f32::INFINITY, f32::min);
+        assert!(closest <= 2.0, "closest detection was {closest}px from the disc center");
+    }
+
+    #[test]
+    fn is_local_maximum_true_at_the_single_peak() {
+        let width = 3;
+        let height = 3;
+        #[rustfmt::skip]
+        let accumulator = vec![
+            1, 1, 1,
+            1, 5, 1,
+            1, 1, 1,
+        ];
+        assert!(is_local_maximum(&accumulator, width, height, 1, 1));
+        assert!(!is_local_maximum(&accumulator, width, height, 0, 0));
+    }
+}
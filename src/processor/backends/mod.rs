@@ -0,0 +1,45 @@
+//! One module per detection algorithm, each behind the [`DetectionBackend`]
+//! trait so `Processor` can swap between them at runtime without a
+//! recompile, for A/B comparison against the same footage.
+
+mod hough;
+mod onnx;
+mod template;
+mod threshold;
+
+pub use hough::HoughBackend;
+pub use onnx::OnnxBackend;
+pub use template::TemplateBackend;
+pub use threshold::ThresholdBackend;
+
+use super::{Detection, DetectionBackendKind, ProcessorSettings};
+use crate::error::AppError;
+use image::RgbImage;
+
+/// A backend's detections, plus how many candidates it considered before
+/// the area filter, so the status bar can show "raw / accepted" the same
+/// way regardless of which backend produced them. `error` is set instead
+/// of panicking when a backend hit a recoverable problem (e.g. a missing
+/// template file) — detections are still whatever the backend managed to
+/// find before or without the failed step.
+pub struct Detections {
+    pub accepted: Vec<Detection>,
+    pub raw_count: usize,
+    pub error: Option<AppError>,
+}
+
+/// A pluggable hole detector. Implementations may keep state between
+/// calls (e.g. a loaded model), so `detect` takes `&mut self`.
+pub trait DetectionBackend {
+    fn detect(&mut self, frame: &RgbImage, settings: &ProcessorSettings) -> Detections;
+}
+
+/// Builds the backend implementation selected by `kind`.
+pub fn make(kind: DetectionBackendKind) -> Box<dyn DetectionBackend> {
+    match kind {
+        DetectionBackendKind::Threshold => Box::new(ThresholdBackend),
+        DetectionBackendKind::Hough => Box::new(HoughBackend),
+        DetectionBackendKind::Template => Box::new(TemplateBackend),
+        DetectionBackendKind::Onnx => Box::new(OnnxBackend),
+    }
+}
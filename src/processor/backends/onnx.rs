@@ -0,0 +1,15 @@
+//! Custom ONNX model backend. No ONNX runtime is wired up yet, so this
+//! falls back to the threshold detector; `settings.onnx` is kept live so
+//! the UI can be built and configured ahead of that integration.
+
+use image::RgbImage;
+
+use super::{DetectionBackend, Detections, ProcessorSettings, ThresholdBackend};
+
+pub struct OnnxBackend;
+
+impl DetectionBackend for OnnxBackend {
+    fn detect(&mut self, frame: &RgbImage, settings: &ProcessorSettings) -> Detections {
+        ThresholdBackend.detect(frame, settings)
+    }
+}
@@ -0,0 +1,120 @@
+//! Template matching: slides a small reference patch of what a hole
+//! looks like (`settings.template.template_path`) over the frame and
+//! flags positions where the match is close enough. Useful for target
+//! papers where holes have a very consistent look (backlit target,
+//! consistent hole punch) and Hough/threshold pick up too much noise.
+
+use std::path::PathBuf;
+
+use image::{GrayImage, RgbImage};
+
+use super::{Detection, DetectionBackend, Detections, ProcessorSettings};
+use crate::error::AppError;
+
+#[derive(Default)]
+pub struct TemplateBackend {
+    /// Cached decode of the last-loaded template, keyed by path so a
+    /// changed `template_path` triggers a reload.
+    cached: Option<(PathBuf, GrayImage)>,
+}
+
+impl TemplateBackend {
+    /// `Ok(None)` when no template is configured yet (not an error —
+    /// just nothing to match against); `Err` when a path was given but
+    /// couldn't be decoded, which the operator can fix by browsing to a
+    /// valid image without restarting.
+    fn template_for(&mut self, path: &PathBuf) -> Result<Option<&GrayImage>, AppError> {
+        if path.as_os_str().is_empty() {
+            return Ok(None);
+        }
+        if self.cached.as_ref().map(|(cached_path, _)| cached_path) != Some(path) {
+            let image = image::open(path)
+                .map_err(|e| AppError::Processing(format!("load template {}: {e}", path.display())))?
+                .to_luma8();
+            self.cached = Some((path.clone(), image));
+        }
+        Ok(self.cached.as_ref().map(|(_, image)| image))
+    }
+}
+
+impl DetectionBackend for TemplateBackend {
+    fn detect(&mut self, frame: &RgbImage, settings: &ProcessorSettings) -> Detections {
+        let path = settings.template.template_path.clone();
+        let template = match self.template_for(&path) {
+            Ok(Some(template)) => template,
+            Ok(None) => return Detections { accepted: Vec::new(), raw_count: 0, error: None },
+            Err(e) => return Detections { accepted: Vec::new(), raw_count: 0, error: Some(e) },
+        };
+        let (tw, th) = template.dimensions();
+        let gray = image::imageops::grayscale(frame);
+        let (width, height) = gray.dimensions();
+        if tw == 0 || th == 0 || tw > width || th > height {
+            return Detections { accepted: Vec::new(), raw_count: 0, error: None };
+        }
+
+        let max_diff = (tw * th) as f32 * 255.0 * settings.template.match_threshold;
+        let mut detections = Vec::new();
+        let mut y = 0;
+        while y + th <= height {
+            let mut x = 0;
+            while x + tw <= width {
+                let diff = sum_abs_diff(&gray, template, x, y);
+                if diff <= max_diff {
+                    let center = (x as f32 + tw as f32 / 2.0, y as f32 + th as f32 / 2.0);
+                    detections.push(Detection { center_px: center, area: (tw * th) as f32 });
+                }
+                x += tw.max(1);
+            }
+            y += th.max(1);
+        }
+
+        let raw_count = detections.len();
+        let accepted = detections
+            .into_iter()
+            .filter(|d| d.area >= settings.min_contour_area && d.area <= settings.max_contour_area)
+            .collect();
+        Detections { accepted, raw_count, error: None }
+    }
+}
+
+/// Sum of absolute grayscale differences between `template` and the
+/// `template`-sized window of `frame` starting at `(x0, y0)`.
+fn sum_abs_diff(frame: &GrayImage, template: &GrayImage, x0: u32, y0: u32) -> f32 {
+    let (tw, th) = template.dimensions();
+    let mut total = 0f32;
+    for ty in 0..th {
+        for tx in 0..tw {
+            let frame_px = frame.get_pixel(x0 + tx, y0 + ty).0[0] as f32;
+            let template_px = template.get_pixel(tx, ty).0[0] as f32;
+            total += (frame_px - template_px).abs();
+        }
+    }
+    total
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::processor::ProcessorSettings;
+
+    #[test]
+    fn no_template_configured_is_not_an_error() {
+        let frame = RgbImage::from_pixel(8, 8, image::Rgb([255, 255, 255]));
+        let detections = TemplateBackend::default().detect(&frame, &ProcessorSettings::default());
+        assert!(detections.accepted.is_empty());
+        assert!(detections.error.is_none());
+    }
+
+    #[test]
+    fn sum_abs_diff_is_zero_for_identical_patches() {
+        let image = GrayImage::from_pixel(4, 4, image::Luma([100]));
+        assert_eq!(sum_abs_diff(&image, &image, 0, 0), 0.0);
+    }
+
+    #[test]
+    fn sum_abs_diff_accumulates_per_pixel_difference() {
+        let frame = GrayImage::from_pixel(2, 2, image::Luma([200]));
+        let template = GrayImage::from_pixel(2, 2, image::Luma([50]));
+        assert_eq!(sum_abs_diff(&frame, &template, 0, 0), 4.0 * 150.0);
+    }
+}
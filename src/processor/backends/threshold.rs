@@ -0,0 +1,67 @@
+//! The original detector: grayscale, threshold, and treat every
+//! surviving pixel as its own single-pixel "contour". Cheap and fine for
+//! high-contrast paper targets under even lighting.
+
+use image::RgbImage;
+
+use super::{Detection, DetectionBackend, Detections, ProcessorSettings};
+
+pub struct ThresholdBackend;
+
+impl DetectionBackend for ThresholdBackend {
+    fn detect(&mut self, frame: &RgbImage, settings: &ProcessorSettings) -> Detections {
+        let gray = image::imageops::grayscale(frame);
+        let contours: Vec<Detection> = gray
+            .enumerate_pixels()
+            .filter(|(_, _, p)| p.0[0] < settings.threshold)
+            .map(|(x, y, _)| Detection { center_px: (x as f32, y as f32), area: 1.0 })
+            .collect();
+
+        let raw_count = contours.len();
+        let accepted = contours
+            .into_iter()
+            .filter(|d| d.area >= settings.min_contour_area && d.area <= settings.max_contour_area)
+            .collect();
+        Detections { accepted, raw_count, error: None }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::processor::ProcessorSettings;
+
+    fn settings(threshold: u8) -> ProcessorSettings {
+        ProcessorSettings { threshold, min_contour_area: 0.0, max_contour_area: 10.0, ..ProcessorSettings::default() }
+    }
+
+    #[test]
+    fn all_white_frame_has_no_detections() {
+        let frame = RgbImage::from_pixel(4, 4, image::Rgb([255, 255, 255]));
+        let detections = ThresholdBackend.detect(&frame, &settings(80));
+        assert!(detections.accepted.is_empty());
+        assert_eq!(detections.raw_count, 0);
+    }
+
+    #[test]
+    fn dark_pixel_below_threshold_is_a_single_pixel_detection() {
+        let mut frame = RgbImage::from_pixel(4, 4, image::Rgb([255, 255, 255]));
+        frame.put_pixel(2, 1, image::Rgb([0, 0, 0]));
+        let detections = ThresholdBackend.detect(&frame, &settings(80));
+        assert_eq!(detections.raw_count, 1);
+        assert_eq!(detections.accepted.len(), 1);
+        assert_eq!(detections.accepted[0].center_px, (2.0, 1.0));
+        assert_eq!(detections.accepted[0].area, 1.0);
+    }
+
+    #[test]
+    fn area_filter_rejects_out_of_range_detections() {
+        let mut frame = RgbImage::from_pixel(4, 4, image::Rgb([255, 255, 255]));
+        frame.put_pixel(0, 0, image::Rgb([0, 0, 0]));
+        let mut settings = settings(80);
+        settings.min_contour_area = 2.0;
+        let detections = ThresholdBackend.detect(&frame, &settings);
+        assert_eq!(detections.raw_count, 1);
+        assert!(detections.accepted.is_empty());
+    }
+}
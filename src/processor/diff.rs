@@ -0,0 +1,22 @@
+//! Frame-difference view: absolute difference between the current frame
+//! and a reference frame, to sanity-check that a flagged "new shot"
+//! corresponds to a real change on paper.
+
+use image::{GrayImage, Luma, RgbImage};
+
+/// Computes the per-pixel absolute grayscale difference between `frame`
+/// and `reference`, returned as a grayscale image the same size as the
+/// inputs (they must match).
+pub fn absolute_diff(frame: &RgbImage, reference: &RgbImage) -> Option<GrayImage> {
+    if frame.dimensions() != reference.dimensions() {
+        return None;
+    }
+    let a = image::imageops::grayscale(frame);
+    let b = image::imageops::grayscale(reference);
+    let mut out = GrayImage::new(a.width(), a.height());
+    for (out_px, (a_px, b_px)) in out.pixels_mut().zip(a.pixels().zip(b.pixels())) {
+        let diff = (a_px.0[0] as i16 - b_px.0[0] as i16).unsigned_abs() as u8;
+        *out_px = Luma([diff]);
+    }
+    Some(out)
+}
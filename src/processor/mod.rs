@@ -0,0 +1,153 @@
+//! Frame processing pipeline: turns a raw frame into candidate hole
+//! detections. The actual algorithm is pluggable — see
+//! [`backends::DetectionBackend`] — so classic threshold/contour, Hough,
+//! template matching and a (currently unwired) ONNX model can all be
+//! selected at runtime from [`ProcessorSettings::backend`] for A/B
+//! comparison without a recompile.
+
+use std::path::PathBuf;
+use std::time::Duration;
+
+use image::RgbImage;
+use serde::{Deserialize, Serialize};
+
+pub mod backends;
+pub mod diff;
+
+/// Which detector `Processor::process` runs; see [`backends::make`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum DetectionBackendKind {
+    #[default]
+    Threshold,
+    Hough,
+    Template,
+    Onnx,
+}
+
+/// A user-supplied ONNX model plus the parameters needed to run it, set
+/// from the "Detection model" panel so a model can be swapped without
+/// recompiling.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OnnxModelConfig {
+    pub model_path: PathBuf,
+    pub input_size: u32,
+    /// Class index -> label, in model output order.
+    pub class_map: Vec<String>,
+    pub confidence_threshold: f32,
+    pub nms_threshold: f32,
+}
+
+impl Default for OnnxModelConfig {
+    fn default() -> Self {
+        Self {
+            model_path: PathBuf::new(),
+            input_size: 640,
+            class_map: vec!["hole".to_string()],
+            confidence_threshold: 0.5,
+            nms_threshold: 0.45,
+        }
+    }
+}
+
+/// A reference patch of what a hole looks like, plus a match threshold,
+/// for [`backends::TemplateBackend`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TemplateConfig {
+    pub template_path: PathBuf,
+    /// Fraction of the maximum possible per-pixel difference a window
+    /// may have and still count as a match; lower is stricter.
+    pub match_threshold: f32,
+}
+
+impl Default for TemplateConfig {
+    fn default() -> Self {
+        Self { template_path: PathBuf::new(), match_threshold: 0.15 }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ProcessorSettings {
+    pub threshold: u8,
+    pub min_contour_area: f32,
+    pub max_contour_area: f32,
+    pub backend: DetectionBackendKind,
+    pub onnx: OnnxModelConfig,
+    pub template: TemplateConfig,
+}
+
+impl Default for ProcessorSettings {
+    fn default() -> Self {
+        Self {
+            threshold: 80,
+            min_contour_area: 8.0,
+            max_contour_area: 400.0,
+            backend: DetectionBackendKind::default(),
+            onnx: OnnxModelConfig::default(),
+            template: TemplateConfig::default(),
+        }
+    }
+}
+
+/// A candidate hole location found in a frame, before it's matched into
+/// the persistent shot history.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct Detection {
+    pub center_px: (f32, f32),
+    pub area: f32,
+}
+
+/// Metrics from the most recent `Processor::process` call, surfaced in the
+/// status bar so operators can see whether the pipeline is healthy.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ProcessorMetrics {
+    pub processing_time: Duration,
+    pub raw_contour_count: usize,
+    pub accepted_hole_count: usize,
+}
+
+pub struct Processor {
+    pub settings: ProcessorSettings,
+    pub last_metrics: ProcessorMetrics,
+    /// Set by the most recent `process()` call when the selected backend
+    /// hit a recoverable problem (e.g. a template file that failed to
+    /// decode); cleared on the next successful run.
+    pub last_error: Option<crate::error::AppError>,
+}
+
+impl Default for Processor {
+    fn default() -> Self {
+        Self {
+            settings: ProcessorSettings::default(),
+            last_metrics: ProcessorMetrics::default(),
+            last_error: None,
+        }
+    }
+}
+
+impl Processor {
+    /// Runs whichever backend `self.settings.backend` selects and
+    /// records timing/counts in `last_metrics`.
+    #[tracing::instrument(skip(self, frame), fields(backend = ?self.settings.backend))]
+    pub fn process(&mut self, frame: &RgbImage) -> Vec<Detection> {
+        puffin::profile_scope!("process");
+        let started = std::time::Instant::now();
+        let mut backend = backends::make(self.settings.backend);
+        let detections = backend.detect(frame, &self.settings);
+        self.last_metrics = ProcessorMetrics {
+            processing_time: started.elapsed(),
+            raw_contour_count: detections.raw_count,
+            accepted_hole_count: detections.accepted.len(),
+        };
+        tracing::debug!(
+            raw = self.last_metrics.raw_contour_count,
+            accepted = self.last_metrics.accepted_hole_count,
+            elapsed_ms = self.last_metrics.processing_time.as_secs_f32() * 1000.0,
+            "frame processed"
+        );
+        if let Some(error) = &detections.error {
+            tracing::warn!(%error, "detection backend error");
+        }
+        self.last_error = detections.error;
+        detections.accepted
+    }
+}
@@ -0,0 +1,46 @@
+//! Project files: a single JSON file capturing everything needed to
+//! reopen a scoring job exactly as it was left — source, crop,
+//! calibration, processor settings, target and the full shot history.
+
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::calibration::ScoringConfig;
+use crate::error::AppError;
+use crate::processor::ProcessorSettings;
+use crate::session::Session;
+use crate::target::TargetType;
+use crate::ui::image_view::CropRect;
+
+/// Where the project's frames came from. Camera-sourced projects only
+/// record that fact, not a full profile — there's no persisted camera
+/// connection settings yet (see [`crate::camera`]) — so reopening one
+/// still requires the operator to reconnect manually.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum ProjectSource {
+    Camera,
+    StaticImage(PathBuf),
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProjectFile {
+    pub source: ProjectSource,
+    pub crop: Option<CropRect>,
+    pub calibration: ScoringConfig,
+    pub processor_settings: ProcessorSettings,
+    pub target: TargetType,
+    pub session: Session,
+}
+
+impl ProjectFile {
+    pub fn save_json(&self, path: &Path) -> Result<(), AppError> {
+        let json = serde_json::to_string_pretty(self).map_err(|e| AppError::storage(path, e.to_string()))?;
+        std::fs::write(path, json).map_err(|e| AppError::storage(path, e.to_string()))
+    }
+
+    pub fn load_json(path: &Path) -> Result<Self, AppError> {
+        let json = std::fs::read_to_string(path).map_err(|e| AppError::storage(path, e.to_string()))?;
+        serde_json::from_str(&json).map_err(|e| AppError::storage(path, e.to_string()))
+    }
+}
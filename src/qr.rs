@@ -0,0 +1,45 @@
+//! Renders a QR code for the mobile companion URL so a coach can join by
+//! scanning it with a phone camera instead of typing an address.
+
+use qrcode::QrCode;
+
+/// A rendered QR code, ready to be uploaded as an egui texture.
+pub struct QrImage {
+    pub size: [usize; 2],
+    pub rgba: Vec<u8>,
+}
+
+/// Encodes `data` as a QR code and rasterizes it at `module_size` pixels
+/// per module (plus a quiet-zone border), black-on-white.
+pub fn encode(data: &str, module_size: usize) -> Result<QrImage, String> {
+    let code = QrCode::new(data).map_err(|e| e.to_string())?;
+    let modules = code.width();
+    let quiet_zone = 4;
+    let side = (modules + quiet_zone * 2) * module_size;
+
+    let mut rgba = vec![255u8; side * side * 4];
+    for y in 0..modules {
+        for x in 0..modules {
+            if code[(x, y)] == qrcode::Color::Dark {
+                for py in 0..module_size {
+                    for px in 0..module_size {
+                        let ix = (x + quiet_zone) * module_size + px;
+                        let iy = (y + quiet_zone) * module_size + py;
+                        let idx = (iy * side + ix) * 4;
+                        rgba[idx] = 0;
+                        rgba[idx + 1] = 0;
+                        rgba[idx + 2] = 0;
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(QrImage { size: [side, side], rgba })
+}
+
+impl QrImage {
+    pub fn to_color_image(&self) -> egui::ColorImage {
+        egui::ColorImage::from_rgba_unmultiplied(self.size, &self.rgba)
+    }
+}
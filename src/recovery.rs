@@ -0,0 +1,71 @@
+//! Crash-recovery autosave: periodically snapshots the in-progress
+//! project to a file under the app's data directory, so a mid-match
+//! crash doesn't lose the scorecard. The file is removed on a clean
+//! shutdown or a successful restore; if it's still there at the next
+//! launch, the operator is offered a chance to restore it.
+
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+use crate::error::AppError;
+use crate::project::ProjectFile;
+
+/// How often the in-progress project is flushed to the recovery file.
+const AUTOSAVE_INTERVAL: Duration = Duration::from_secs(30);
+
+fn recovery_path() -> Option<PathBuf> {
+    let dirs = directories::ProjectDirs::from("com", "precision-scorer", "precision-scorer")?;
+    Some(dirs.data_dir().join("recovery.json"))
+}
+
+/// Ticks the autosave clock, polled once per frame.
+pub struct AutosaveTimer {
+    last_saved_at: Instant,
+}
+
+impl Default for AutosaveTimer {
+    fn default() -> Self {
+        Self { last_saved_at: Instant::now() }
+    }
+}
+
+impl AutosaveTimer {
+    /// `true` at most once every [`AUTOSAVE_INTERVAL`]; resets the clock
+    /// when it returns `true` so the caller can build and write a
+    /// snapshot only when actually due.
+    pub fn poll(&mut self) -> bool {
+        if self.last_saved_at.elapsed() < AUTOSAVE_INTERVAL {
+            return false;
+        }
+        self.last_saved_at = Instant::now();
+        true
+    }
+}
+
+/// Writes `project` to the recovery file, creating its parent directory
+/// if needed.
+pub fn save(project: &ProjectFile) -> Result<(), AppError> {
+    let Some(path) = recovery_path() else {
+        return Err(AppError::storage(&PathBuf::from("recovery.json"), "no data directory available"));
+    };
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir).map_err(|e| AppError::storage(dir, e.to_string()))?;
+    }
+    project.save_json(&path)
+}
+
+/// Loads the recovery file left behind by an unclean exit, if any. Files
+/// that fail to parse are treated as if none existed rather than
+/// blocking startup.
+pub fn load_pending() -> Option<ProjectFile> {
+    let path = recovery_path()?;
+    ProjectFile::load_json(&path).ok()
+}
+
+/// Removes the recovery file after a clean shutdown or a successful
+/// restore, so it isn't offered again next launch.
+pub fn clear() {
+    if let Some(path) = recovery_path() {
+        let _ = std::fs::remove_file(path);
+    }
+}
@@ -0,0 +1,113 @@
+//! Splits capture from scoring across two machines: a `FrameSender` runs
+//! at the target line and streams captured frames over TCP, while a
+//! `FrameReceiver` runs at the firing line and feeds them into
+//! [`crate::app::MyApp::current_frame`] exactly as a local [`crate::camera::Camera`]
+//! would. Frames are JPEG-compressed and length-prefixed, one connection
+//! per receiver, following the same "spawn a thread per connection"
+//! shape as [`crate::mjpeg`].
+
+use std::io::{self, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::sync::mpsc::{channel, Receiver, Sender};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+use image::codecs::jpeg::JpegEncoder;
+use image::RgbImage;
+
+/// Runs at the target line: accepts connections from firing-line
+/// receivers and streams JPEG frames to each of them.
+pub struct FrameSender {
+    clients: Arc<Mutex<Vec<Sender<Vec<u8>>>>>,
+    addr: String,
+}
+
+impl FrameSender {
+    pub fn start(addr: &str) -> io::Result<Self> {
+        let listener = TcpListener::bind(addr)?;
+        let addr = listener.local_addr()?.to_string();
+        let clients: Arc<Mutex<Vec<Sender<Vec<u8>>>>> = Arc::new(Mutex::new(Vec::new()));
+        let accept_clients = clients.clone();
+        thread::spawn(move || {
+            for stream in listener.incoming().flatten() {
+                let (tx, rx) = channel::<Vec<u8>>();
+                accept_clients.lock().unwrap().push(tx);
+                thread::spawn(move || {
+                    let mut stream = stream;
+                    for jpeg in rx {
+                        let len = (jpeg.len() as u32).to_le_bytes();
+                        if stream.write_all(&len).is_err() || stream.write_all(&jpeg).is_err() {
+                            break;
+                        }
+                    }
+                });
+            }
+        });
+        Ok(Self { clients, addr })
+    }
+
+    pub fn addr(&self) -> &str {
+        &self.addr
+    }
+
+    /// JPEG-encodes `frame` and pushes it to every connected receiver,
+    /// dropping any whose connection has gone away.
+    pub fn publish_frame(&self, frame: &RgbImage) {
+        let mut jpeg = Vec::new();
+        if JpegEncoder::new_with_quality(&mut jpeg, 85)
+            .encode(frame.as_raw(), frame.width(), frame.height(), image::ColorType::Rgb8)
+            .is_err()
+        {
+            return;
+        }
+        self.clients.lock().unwrap().retain(|tx| tx.send(jpeg.clone()).is_ok());
+    }
+}
+
+/// Runs at the firing line: connects to a `FrameSender` and decodes
+/// incoming frames on a background thread so [`Self::poll_frame`] never
+/// blocks the UI thread.
+pub struct FrameReceiver {
+    rx: Receiver<RgbImage>,
+    addr: String,
+}
+
+impl FrameReceiver {
+    pub fn connect(addr: &str) -> io::Result<Self> {
+        let stream = TcpStream::connect(addr)?;
+        let (tx, rx) = channel();
+        thread::spawn(move || {
+            let mut stream = stream;
+            loop {
+                let mut len_buf = [0u8; 4];
+                if stream.read_exact(&mut len_buf).is_err() {
+                    break;
+                }
+                let len = u32::from_le_bytes(len_buf) as usize;
+                let mut jpeg = vec![0u8; len];
+                if stream.read_exact(&mut jpeg).is_err() {
+                    break;
+                }
+                let Ok(image) = image::load_from_memory(&jpeg) else { break };
+                if tx.send(image.to_rgb8()).is_err() {
+                    break;
+                }
+            }
+        });
+        Ok(Self { rx, addr: addr.to_string() })
+    }
+
+    pub fn addr(&self) -> &str {
+        &self.addr
+    }
+
+    /// Non-blocking; returns the most recently decoded frame, discarding
+    /// any older backlog so the display never lags behind the target line.
+    pub fn poll_frame(&self) -> Option<RgbImage> {
+        let mut latest = None;
+        while let Ok(frame) = self.rx.try_recv() {
+            latest = Some(frame);
+        }
+        latest
+    }
+}
@@ -0,0 +1,99 @@
+//! Deterministic record-and-replay: a [`Recorder`] captures every frame
+//! pushed into the pipeline plus every event published on
+//! [`crate::events::EventBus`] (shots, detections, calibration changes)
+//! to a single newline-delimited JSON file. A [`Player`] reads that file
+//! back in order, so a user can send one file that reproduces a
+//! mis-scored shot exactly, instead of describing it after the fact.
+//!
+//! Frames are JPEG-encoded to keep recordings a manageable size; replay
+//! feeds them back through the same [`crate::processor::Processor`] the
+//! live app uses, so a fixed bug is verifiable by re-running the
+//! recording rather than trusting a written repro.
+
+use std::fs::File;
+use std::io::{BufRead, BufReader, BufWriter, Write};
+use std::path::Path;
+
+use image::codecs::jpeg::JpegEncoder;
+use image::RgbImage;
+use serde::{Deserialize, Serialize};
+
+use crate::error::AppError;
+use crate::events::AppEvent;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum ReplayEntry {
+    Frame { jpeg: Vec<u8> },
+    Event(AppEvent),
+}
+
+pub struct Recorder {
+    writer: BufWriter<File>,
+}
+
+impl Recorder {
+    pub fn start(path: &Path) -> Result<Self, AppError> {
+        let file = File::create(path).map_err(|e| AppError::storage(path, e.to_string()))?;
+        Ok(Self { writer: BufWriter::new(file) })
+    }
+
+    /// JPEG-encodes `frame` and appends it as the next entry.
+    pub fn record_frame(&mut self, frame: &RgbImage) {
+        let mut jpeg = Vec::new();
+        let encoded = JpegEncoder::new_with_quality(&mut jpeg, 90)
+            .encode(frame.as_raw(), frame.width(), frame.height(), image::ColorType::Rgb8);
+        if encoded.is_ok() {
+            self.write_entry(&ReplayEntry::Frame { jpeg });
+        } else {
+            tracing::warn!("replay: failed to encode frame, dropping it from the recording");
+        }
+    }
+
+    pub fn record_event(&mut self, event: &AppEvent) {
+        self.write_entry(&ReplayEntry::Event(event.clone()));
+    }
+
+    fn write_entry(&mut self, entry: &ReplayEntry) {
+        match serde_json::to_string(entry) {
+            Ok(json) => {
+                if let Err(e) = writeln!(self.writer, "{json}") {
+                    tracing::warn!(error = %e, "replay: failed to write entry");
+                }
+            }
+            Err(e) => tracing::warn!(error = %e, "replay: failed to serialize entry"),
+        }
+    }
+}
+
+/// Reads a recording back one entry at a time, in the order it was
+/// written.
+pub struct Player {
+    lines: std::io::Lines<BufReader<File>>,
+}
+
+impl Player {
+    pub fn load(path: &Path) -> Result<Self, AppError> {
+        let file = File::open(path).map_err(|e| AppError::storage(path, e.to_string()))?;
+        Ok(Self { lines: BufReader::new(file).lines() })
+    }
+
+    /// Decodes a JPEG frame entry back into an [`RgbImage`].
+    pub fn decode_frame(jpeg: &[u8]) -> Result<RgbImage, AppError> {
+        image::load_from_memory(jpeg)
+            .map(|img| img.to_rgb8())
+            .map_err(|e| AppError::Camera(format!("replay: bad frame: {e}")))
+    }
+}
+
+impl Iterator for Player {
+    type Item = ReplayEntry;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let line = self.lines.next()?.ok()?;
+            if let Ok(entry) = serde_json::from_str(&line) {
+                return Some(entry);
+            }
+        }
+    }
+}
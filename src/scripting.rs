@@ -0,0 +1,160 @@
+//! Rhai scripting hooks: a user-supplied `.rhai` script can define
+//! `on_shot(shot)`, `on_series_complete(series)` and
+//! `on_session_end(session)` functions, called whenever those events
+//! happen. Scripts can call back into the app via a small set of
+//! exposed functions (`write_file`, `http_post`, `set_message`) to
+//! automate workflows — logging to a spreadsheet, pinging a webhook,
+//! flashing a custom status line — without forking the crate.
+//!
+//! A script is a shareable artifact — it can arrive from another club
+//! member or a public gist — so it's treated as untrusted: `write_file`
+//! is confined to a `script-output` directory under the app's data
+//! directory rather than being able to write anywhere the process can
+//! reach (see [`resolve_output_path`]), the engine is capped at
+//! [`MAX_SCRIPT_OPERATIONS`] so a runaway loop can't hang the UI thread
+//! hooks run on, and `http_post` carries its own [`SCRIPT_HTTP_TIMEOUT`]
+//! so a slow endpoint can't do the same.
+
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use rhai::{Dynamic, Engine, Map, Scope, AST};
+
+use crate::error::AppError;
+use crate::session::{Series, Session, Shot};
+
+/// Rhai operation budget for a single hook call. Scripts are an
+/// untrusted, shareable artifact (see the module doc above), so a
+/// runaway `while true {}` needs to hit a hard ceiling and return
+/// control to the UI thread instead of freezing the app.
+const MAX_SCRIPT_OPERATIONS: u64 = 10_000_000;
+
+/// How long `http_post` waits for the request to complete before giving
+/// up, so a slow or unresponsive endpoint can't block the UI thread
+/// indefinitely the same way an unbounded op count would.
+const SCRIPT_HTTP_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// A compiled script plus the engine it was compiled with (functions
+/// registered on the engine, like `set_message`, close over state the
+/// hooks need to reach back into).
+pub struct ScriptEngine {
+    engine: Engine,
+    ast: AST,
+    /// Filled by the script's `set_message(text)` calls; drained once
+    /// per frame by the caller into its own notification center.
+    messages: Arc<Mutex<Vec<String>>>,
+}
+
+impl ScriptEngine {
+    pub fn load(path: &Path) -> Result<Self, AppError> {
+        let mut engine = Engine::new();
+        engine.set_max_operations(MAX_SCRIPT_OPERATIONS);
+        let messages: Arc<Mutex<Vec<String>>> = Arc::new(Mutex::new(Vec::new()));
+
+        let output_dir = script_output_dir();
+        if let Some(dir) = &output_dir {
+            let _ = std::fs::create_dir_all(dir);
+        }
+        engine.register_fn("write_file", move |path: &str, content: &str| -> bool {
+            let Some(dir) = &output_dir else { return false };
+            let Some(resolved) = resolve_output_path(dir, path) else { return false };
+            std::fs::write(resolved, content).is_ok()
+        });
+        let http_agent = ureq::AgentBuilder::new().timeout(SCRIPT_HTTP_TIMEOUT).build();
+        engine.register_fn("http_post", move |url: &str, body: &str| -> bool {
+            http_agent.post(url).send_string(body).is_ok()
+        });
+        {
+            let messages = messages.clone();
+            engine.register_fn("set_message", move |text: &str| {
+                messages.lock().unwrap().push(text.to_string());
+            });
+        }
+
+        let ast = engine
+            .compile_file(path.to_path_buf())
+            .map_err(|e| AppError::storage(path, e.to_string()))?;
+        Ok(Self { engine, ast, messages })
+    }
+
+    /// Drains messages queued by the script's `set_message` calls since
+    /// the last poll.
+    pub fn take_messages(&self) -> Vec<String> {
+        std::mem::take(&mut self.messages.lock().unwrap())
+    }
+
+    pub fn on_shot(&mut self, shot: &Shot) {
+        self.call_hook("on_shot", shot_to_map(shot));
+    }
+
+    pub fn on_series_complete(&mut self, series: &Series) {
+        self.call_hook("on_series_complete", series_to_map(series));
+    }
+
+    pub fn on_session_end(&mut self, session: &Session) {
+        self.call_hook("on_session_end", session_to_map(session));
+    }
+
+    /// Calls `name(arg)` if the script defines it; missing hooks are
+    /// silently skipped, since a script is free to only care about some
+    /// events.
+    fn call_hook(&mut self, name: &str, arg: Map) {
+        if !self.ast.iter_functions().any(|f| f.name == name && f.params.len() == 1) {
+            return;
+        }
+        let mut scope = Scope::new();
+        if let Err(e) = self.engine.call_fn::<Dynamic>(&mut scope, &self.ast, name, (arg,)) {
+            tracing::warn!(hook = name, error = %e, "script hook failed");
+        }
+    }
+}
+
+/// Where `write_file` is confined to. `None` if the platform has no
+/// standard data directory (`write_file` then always fails closed).
+fn script_output_dir() -> Option<PathBuf> {
+    let dirs = directories::ProjectDirs::from("com", "precision-scorer", "precision-scorer")?;
+    Some(dirs.data_dir().join("script-output"))
+}
+
+/// Joins a script-provided path onto `dir`, rejecting anything that
+/// would escape it — an absolute path or a `..` component — so a script
+/// can't reach outside its sandbox via a crafted `path` argument.
+fn resolve_output_path(dir: &Path, requested: &str) -> Option<PathBuf> {
+    let requested = Path::new(requested);
+    if requested.is_absolute() || requested.components().any(|c| matches!(c, std::path::Component::ParentDir)) {
+        return None;
+    }
+    Some(dir.join(requested))
+}
+
+fn shot_to_map(shot: &Shot) -> Map {
+    let mut map = Map::new();
+    map.insert("number".into(), (shot.number as i64).into());
+    map.insert("x_mm".into(), (shot.x_mm as f64).into());
+    map.insert("y_mm".into(), (shot.y_mm as f64).into());
+    map.insert("value".into(), (shot.value as f64).into());
+    map.insert("is_x".into(), shot.is_x.into());
+    map.insert("flagged".into(), shot.flagged.into());
+    map.insert("manual".into(), shot.manual.into());
+    map
+}
+
+fn series_to_map(series: &Series) -> Map {
+    let mut map = Map::new();
+    map.insert("label".into(), series.label.clone().into());
+    map.insert("shot_count".into(), (series.shots.len() as i64).into());
+    map.insert("total".into(), (series.total() as f64).into());
+    map.insert("x_count".into(), (series.x_count() as i64).into());
+    map
+}
+
+fn session_to_map(session: &Session) -> Map {
+    let mut map = Map::new();
+    map.insert("shooter".into(), session.shooter.name.clone().into());
+    map.insert("club".into(), session.shooter.club.clone().into());
+    map.insert("series_count".into(), (session.series.len() as i64).into());
+    map.insert("total".into(), (session.total() as f64).into());
+    map.insert("x_count".into(), (session.x_count() as i64).into());
+    map
+}
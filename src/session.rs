@@ -0,0 +1,115 @@
+//! Session, series and shot data model.
+//!
+//! This is the persistent record of what happened during a scoring session,
+//! independent of how the shots were detected (camera, static image, manual
+//! entry). The UI and exporters both read from `Session`.
+
+use std::path::Path;
+use std::time::SystemTime;
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::AppError;
+
+/// A single scored hit on the target.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Shot {
+    /// 1-based index within the session.
+    pub number: usize,
+    /// Position relative to the target center, in millimeters.
+    pub x_mm: f32,
+    pub y_mm: f32,
+    /// Decimal score value (e.g. 10.9 for an inner-ten).
+    pub value: f32,
+    /// True if the value is an "X" / inner-ten ring for X-count purposes.
+    pub is_x: bool,
+    pub timestamp: SystemTime,
+    /// Free-text condition annotation, e.g. "wind gust", "bad trigger".
+    /// Persists with the session and shows up in exports and reports.
+    pub note: Option<String>,
+    /// Marked by the shooter/coach as suspicious (double hole, cross-shot).
+    pub flagged: bool,
+    /// True if the shot was entered by hand rather than detected.
+    pub manual: bool,
+    /// Elapsed time in seconds reported by an external shot timer for
+    /// this shot, if one is connected (see `crate::shot_timer`).
+    pub timer_split_secs: Option<f32>,
+    /// True if an acoustic sensor detection was fused with this shot,
+    /// either confirming an optical detection or standing in for one
+    /// that was missed (see `crate::acoustic`).
+    pub acoustic_confirmed: bool,
+}
+
+/// A group of shots fired together, e.g. one string of 10.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Series {
+    pub label: String,
+    pub shots: Vec<Shot>,
+}
+
+impl Series {
+    pub fn total(&self) -> f32 {
+        self.shots.iter().map(|s| s.value).sum()
+    }
+
+    pub fn x_count(&self) -> usize {
+        self.shots.iter().filter(|s| s.is_x).count()
+    }
+}
+
+/// Identifies who fired the session, for scorecards and exports.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Shooter {
+    pub name: String,
+    pub club: String,
+}
+
+/// A complete scoring session: shooter, date and all series fired.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Session {
+    pub shooter: Shooter,
+    pub started_at: SystemTime,
+    pub series: Vec<Series>,
+}
+
+impl Session {
+    pub fn new(shooter: Shooter) -> Self {
+        Self {
+            shooter,
+            started_at: SystemTime::now(),
+            series: Vec::new(),
+        }
+    }
+
+    pub fn total(&self) -> f32 {
+        self.series.iter().map(Series::total).sum()
+    }
+
+    pub fn x_count(&self) -> usize {
+        self.series.iter().map(Series::x_count).sum()
+    }
+
+    pub fn all_shots(&self) -> impl Iterator<Item = &Shot> {
+        self.series.iter().flat_map(|s| s.shots.iter())
+    }
+
+    /// Removes every shot whose `number` is in `numbers`, e.g. for bulk
+    /// delete/exclude from the shot table.
+    pub fn remove_shots(&mut self, numbers: &std::collections::HashSet<usize>) {
+        for series in &mut self.series {
+            series.shots.retain(|s| !numbers.contains(&s.number));
+        }
+    }
+
+    /// Saves the session as pretty-printed JSON, e.g. for later comparison
+    /// or archival.
+    pub fn save_json(&self, path: &Path) -> Result<(), AppError> {
+        let json = serde_json::to_string_pretty(self).map_err(|e| AppError::storage(path, e.to_string()))?;
+        std::fs::write(path, json).map_err(|e| AppError::storage(path, e.to_string()))
+    }
+
+    pub fn load_json(path: &Path) -> Result<Self, AppError> {
+        let json = std::fs::read_to_string(path).map_err(|e| AppError::storage(path, e.to_string()))?;
+        serde_json::from_str(&json).map_err(|e| AppError::storage(path, e.to_string()))
+    }
+}
@@ -0,0 +1,55 @@
+//! Application-wide settings persisted across runs (overlay appearance,
+//! and anything added by later features).
+
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::AppError;
+use crate::mqtt::MqttConfig;
+use crate::overlay::OverlaySettings;
+use crate::shot_trigger::ShotTriggerConfig;
+use crate::sync::SyncConfig;
+use crate::ui::theme::ThemeSettings;
+use crate::units::UnitSettings;
+use crate::webhook::WebhookConfig;
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct Settings {
+    pub overlay: OverlaySettings,
+    pub theme: ThemeSettings,
+    pub mqtt: MqttConfig,
+    pub sync: SyncConfig,
+    pub webhook: WebhookConfig,
+    pub shot_trigger: ShotTriggerConfig,
+    pub units: UnitSettings,
+}
+
+impl Settings {
+    fn path() -> Option<PathBuf> {
+        let dirs = directories::ProjectDirs::from("com", "precision-scorer", "precision-scorer")?;
+        Some(dirs.config_dir().join("settings.json"))
+    }
+
+    pub fn load() -> Self {
+        let Some(path) = Self::path() else { return Self::default() };
+        let loaded = std::fs::read_to_string(&path).ok().and_then(|json| serde_json::from_str(&json).ok());
+        loaded.unwrap_or_else(|| {
+            if path.exists() {
+                tracing::warn!(path = %path.display(), "failed to load settings, using defaults");
+            }
+            Self::default()
+        })
+    }
+
+    pub fn save(&self) -> Result<(), AppError> {
+        let Some(path) = Self::path() else {
+            return Err(AppError::storage(&PathBuf::from("settings.json"), "no config directory available"));
+        };
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent).map_err(|e| AppError::storage(&path, e.to_string()))?;
+        }
+        let json = serde_json::to_string_pretty(self).map_err(|e| AppError::storage(&path, e.to_string()))?;
+        std::fs::write(&path, json).map_err(|e| AppError::storage(&path, e.to_string()))
+    }
+}
@@ -0,0 +1,63 @@
+//! Connects to a shot timer over serial (most Bluetooth timers, e.g. the
+//! CED7000/PACT clones, expose the same interface as a Bluetooth-serial
+//! (SPP) port once paired, so one client covers both). Assumes a generic
+//! wire format — one ASCII line per shot, the elapsed time in seconds
+//! since the timer's start beep (e.g. `"12.34\n"`) — since each vendor's
+//! real protocol differs and this is the common denominator taught by
+//! most clones. Each split is matched to the next detected hole so the
+//! session ends up with combined time+accuracy data.
+
+use std::io::{self, BufRead, BufReader};
+use std::sync::mpsc::{self, Receiver};
+use std::thread;
+use std::time::Duration;
+
+use serialport::SerialPort;
+
+pub struct ShotTimerClient {
+    rx: Receiver<f32>,
+    port_name: String,
+}
+
+impl ShotTimerClient {
+    /// Opens `port_name` (e.g. `"/dev/rfcomm0"` for a paired Bluetooth
+    /// timer, or `"COM5"`/`"/dev/ttyUSB0"` for a wired one) and starts
+    /// reading splits in a background thread.
+    pub fn connect(port_name: &str, baud_rate: u32) -> io::Result<Self> {
+        let port = serialport::new(port_name, baud_rate)
+            .timeout(Duration::from_secs(3600))
+            .open()
+            .map_err(|e| io::Error::new(io::ErrorKind::Other, e.to_string()))?;
+        let (tx, rx) = mpsc::channel();
+        thread::spawn(move || read_loop(port, tx));
+        Ok(Self { rx, port_name: port_name.to_string() })
+    }
+
+    pub fn port_name(&self) -> &str {
+        &self.port_name
+    }
+
+    /// Returns the next buffered split, if any, without blocking.
+    pub fn poll_split(&self) -> Option<f32> {
+        self.rx.try_recv().ok()
+    }
+}
+
+fn read_loop(port: Box<dyn SerialPort>, tx: mpsc::Sender<f32>) {
+    let mut reader = BufReader::new(port);
+    let mut line = String::new();
+    loop {
+        line.clear();
+        match reader.read_line(&mut line) {
+            Ok(0) => break,
+            Ok(_) => {
+                if let Ok(split) = line.trim().parse::<f32>() {
+                    if tx.send(split).is_err() {
+                        break;
+                    }
+                }
+            }
+            Err(_) => break,
+        }
+    }
+}
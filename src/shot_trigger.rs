@@ -0,0 +1,52 @@
+//! Fires an arbitrary HTTP request after every detected shot, for home
+//! automation hubs (Home Assistant, Node-RED, ...) that want to react in
+//! near real time — blink lane lights, log to a database, trigger a
+//! second camera. Unlike [`crate::webhook`], which posts a human-readable
+//! milestone message with an attached image, this posts a small JSON
+//! body built from a user-editable template on *every* shot.
+
+use std::io;
+
+use serde::{Deserialize, Serialize};
+
+use crate::session::Shot;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ShotTriggerConfig {
+    pub enabled: bool,
+    pub url: String,
+    /// JSON body template; `{number}`, `{value}`, `{is_x}`, `{x_mm}` and
+    /// `{y_mm}` are substituted with the shot's fields before sending.
+    pub body_template: String,
+}
+
+impl Default for ShotTriggerConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            url: String::new(),
+            body_template: r#"{"number":{number},"value":{value},"is_x":{is_x},"x_mm":{x_mm},"y_mm":{y_mm}}"#
+                .to_string(),
+        }
+    }
+}
+
+/// Renders `config.body_template` for `shot` and POSTs it as raw JSON;
+/// a no-op when disabled so callers can call this unconditionally.
+pub fn fire(config: &ShotTriggerConfig, shot: &Shot) -> io::Result<()> {
+    if !config.enabled {
+        return Ok(());
+    }
+    let body = config
+        .body_template
+        .replace("{number}", &shot.number.to_string())
+        .replace("{value}", &format!("{:.1}", shot.value))
+        .replace("{is_x}", &shot.is_x.to_string())
+        .replace("{x_mm}", &format!("{:.2}", shot.x_mm))
+        .replace("{y_mm}", &format!("{:.2}", shot.y_mm));
+    ureq::post(&config.url)
+        .set("Content-Type", "application/json")
+        .send_string(&body)
+        .map_err(|e| io::Error::new(io::ErrorKind::Other, e.to_string()))?;
+    Ok(())
+}
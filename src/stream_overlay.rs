@@ -0,0 +1,189 @@
+//! Serves an OBS-ready browser-source page for live-streamed matches: a
+//! transparent HTML overlay showing the running total and last shot,
+//! polling `/state` for updates so it stays in sync without a page
+//! reload. The same server also serves `/mobile`, a phone-friendly
+//! companion page plotting every shot on the virtual target, so a coach
+//! can follow along by scanning the QR code shown in the app (see
+//! `crate::qr`) without installing anything.
+
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+use serde::Serialize;
+use tiny_http::{Response, Server};
+
+use crate::session::Session;
+use crate::target::TargetType;
+
+#[derive(Serialize)]
+struct ShotPoint {
+    x_mm: f32,
+    y_mm: f32,
+    is_x: bool,
+}
+
+#[derive(Serialize, Default)]
+struct OverlayState {
+    total: f32,
+    x_count: usize,
+    last_value: Option<f32>,
+    last_is_x: bool,
+    shot_count: usize,
+    ring_radii_mm: Vec<f32>,
+    shots: Vec<ShotPoint>,
+}
+
+pub struct StreamOverlayServer {
+    shared: Arc<Mutex<OverlayState>>,
+    addr: String,
+}
+
+impl StreamOverlayServer {
+    pub fn start(addr: &str) -> std::io::Result<Self> {
+        let server = Server::http(addr)
+            .map_err(|e| std::io::Error::new(std::io::ErrorKind::AddrInUse, e.to_string()))?;
+        let shared = Arc::new(Mutex::new(OverlayState::default()));
+        let worker_shared = Arc::clone(&shared);
+        thread::spawn(move || serve(server, worker_shared));
+        Ok(Self { shared, addr: addr.to_string() })
+    }
+
+    pub fn addr(&self) -> &str {
+        &self.addr
+    }
+
+    /// Called once per UI frame to refresh the state the overlay and
+    /// mobile companion pages poll for.
+    pub fn publish(&self, session: &Session, target: &TargetType) {
+        let Ok(mut guard) = self.shared.lock() else { return };
+        let last = session.all_shots().last();
+        *guard = OverlayState {
+            total: session.total(),
+            x_count: session.x_count(),
+            last_value: last.map(|s| s.value),
+            last_is_x: last.map(|s| s.is_x).unwrap_or(false),
+            shot_count: session.all_shots().count(),
+            ring_radii_mm: target.ring_radii_mm.clone(),
+            shots: session
+                .all_shots()
+                .map(|s| ShotPoint { x_mm: s.x_mm, y_mm: s.y_mm, is_x: s.is_x })
+                .collect(),
+        };
+    }
+
+    /// URL of the mobile companion page, for display alongside a QR code.
+    pub fn mobile_url(&self) -> String {
+        format!("http://{}/mobile", self.addr)
+    }
+}
+
+fn serve(server: Server, shared: Arc<Mutex<OverlayState>>) {
+    for request in server.incoming_requests() {
+        let response = match request.url() {
+            "/" | "/overlay" => Response::from_string(OVERLAY_HTML)
+                .with_header("Content-Type: text/html; charset=utf-8".parse().unwrap()),
+            "/mobile" => Response::from_string(MOBILE_HTML)
+                .with_header("Content-Type: text/html; charset=utf-8".parse().unwrap()),
+            "/state" => {
+                let json = shared
+                    .lock()
+                    .ok()
+                    .and_then(|s| serde_json::to_string(&*s).ok())
+                    .unwrap_or_else(|| "{}".to_string());
+                Response::from_string(json)
+                    .with_header("Content-Type: application/json".parse().unwrap())
+            }
+            _ => Response::from_string("not found").with_status_code(404),
+        };
+        let _ = request.respond(response);
+    }
+}
+
+/// Transparent by default so OBS's browser source composites over the
+/// stream without needing a chroma key.
+const OVERLAY_HTML: &str = r#"<!doctype html>
+<html><head><meta charset="utf-8">
+<style>
+  html, body { margin: 0; background: transparent; font-family: sans-serif; color: white; }
+  #overlay { padding: 12px 20px; }
+  #total { font-size: 48px; font-weight: bold; text-shadow: 0 0 6px black; }
+  #last { font-size: 28px; text-shadow: 0 0 6px black; }
+</style></head>
+<body>
+  <div id="overlay">
+    <div id="total">-</div>
+    <div id="last"></div>
+  </div>
+  <script>
+    async function poll() {
+      try {
+        const res = await fetch('/state');
+        const s = await res.json();
+        document.getElementById('total').textContent = s.total.toFixed(1) + '  (' + s.x_count + 'X)';
+        document.getElementById('last').textContent = s.last_value != null
+          ? 'Last: ' + s.last_value.toFixed(1) + (s.last_is_x ? ' (X)' : '')
+          : '';
+      } catch (e) {}
+      setTimeout(poll, 300);
+    }
+    poll();
+  </script>
+</body></html>"#;
+
+/// Phone-sized companion page: a canvas plot of every shot on the target
+/// plus the running score, laid out large enough to read at arm's length.
+const MOBILE_HTML: &str = r#"<!doctype html>
+<html><head><meta charset="utf-8">
+<meta name="viewport" content="width=device-width, initial-scale=1">
+<style>
+  html, body { margin: 0; background: #111; font-family: sans-serif; color: white; }
+  #score { padding: 10px 0; text-align: center; }
+  #total { font-size: 40px; font-weight: bold; }
+  #sub { font-size: 18px; color: #ccc; }
+  canvas { display: block; margin: 0 auto; background: #1c1c1c; touch-action: none; }
+</style></head>
+<body>
+  <div id="score">
+    <div id="total">-</div>
+    <div id="sub"></div>
+  </div>
+  <canvas id="target" width="360" height="360"></canvas>
+  <script>
+    const canvas = document.getElementById('target');
+    const ctx = canvas.getContext('2d');
+
+    function draw(s) {
+      const w = canvas.width, h = canvas.height;
+      const cx = w / 2, cy = h / 2;
+      ctx.clearRect(0, 0, w, h);
+      const maxRadius = s.ring_radii_mm.length ? s.ring_radii_mm[0] : 1;
+      const scale = (Math.min(w, h) / 2 - 10) / maxRadius;
+
+      ctx.strokeStyle = '#555';
+      for (const r of s.ring_radii_mm) {
+        ctx.beginPath();
+        ctx.arc(cx, cy, r * scale, 0, Math.PI * 2);
+        ctx.stroke();
+      }
+
+      for (const shot of s.shots) {
+        ctx.beginPath();
+        ctx.arc(cx + shot.x_mm * scale, cy - shot.y_mm * scale, 4, 0, Math.PI * 2);
+        ctx.fillStyle = shot.is_x ? '#ffcc00' : '#ff3b30';
+        ctx.fill();
+      }
+    }
+
+    async function poll() {
+      try {
+        const res = await fetch('/state');
+        const s = await res.json();
+        document.getElementById('total').textContent = s.total.toFixed(1) + '  (' + s.x_count + 'X)';
+        document.getElementById('sub').textContent = s.shot_count + ' shots';
+        draw(s);
+      } catch (e) {}
+      setTimeout(poll, 300);
+    }
+    poll();
+  </script>
+</body></html>"#;
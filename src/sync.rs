@@ -0,0 +1,94 @@
+//! Optional cloud sync for completed sessions, so training logged at
+//! different locations ends up in one place: either a JSON file dropped
+//! into a WebDAV folder, or a summary row appended to a Google Sheet.
+
+use std::io;
+use std::time::UNIX_EPOCH;
+
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine as _;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use crate::session::Session;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum SyncBackend {
+    #[default]
+    Disabled,
+    WebDav,
+    GoogleSheets,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SyncConfig {
+    pub backend: SyncBackend,
+    pub webdav_url: String,
+    pub webdav_username: String,
+    pub webdav_password: String,
+    pub google_sheets_id: String,
+    pub google_sheets_api_key: String,
+}
+
+impl Default for SyncConfig {
+    fn default() -> Self {
+        Self {
+            backend: SyncBackend::default(),
+            webdav_url: String::new(),
+            webdav_username: String::new(),
+            webdav_password: String::new(),
+            google_sheets_id: String::new(),
+            google_sheets_api_key: String::new(),
+        }
+    }
+}
+
+/// Uploads `session` as a pretty-printed JSON file named by its start
+/// timestamp, so re-running sync never overwrites an earlier session.
+pub fn upload_webdav(config: &SyncConfig, session: &Session) -> io::Result<()> {
+    let json = serde_json::to_string_pretty(session)?;
+    let started_at_secs = session.started_at.duration_since(UNIX_EPOCH).unwrap_or_default().as_secs();
+    let url = format!("{}/session-{started_at_secs}.json", config.webdav_url.trim_end_matches('/'));
+    let auth = BASE64.encode(format!("{}:{}", config.webdav_username, config.webdav_password));
+
+    ureq::put(&url)
+        .set("Authorization", &format!("Basic {auth}"))
+        .set("Content-Type", "application/json")
+        .send_string(&json)
+        .map_err(|e| io::Error::new(io::ErrorKind::Other, e.to_string()))?;
+    Ok(())
+}
+
+/// Appends one summary row (shooter, timestamp, total, X-count, series
+/// count) to the sheet via the Sheets API v4 `values:append` endpoint.
+pub fn append_google_sheets_row(config: &SyncConfig, session: &Session) -> io::Result<()> {
+    let started_at_secs = session.started_at.duration_since(UNIX_EPOCH).unwrap_or_default().as_secs();
+    let url = format!(
+        "https://sheets.googleapis.com/v4/spreadsheets/{}/values/Sessions!A:E:append?valueInputOption=RAW&key={}",
+        config.google_sheets_id, config.google_sheets_api_key
+    );
+    let body = json!({
+        "values": [[
+            session.shooter.name,
+            started_at_secs,
+            session.total(),
+            session.x_count(),
+            session.series.len(),
+        ]]
+    });
+
+    ureq::post(&url)
+        .send_json(body)
+        .map_err(|e| io::Error::new(io::ErrorKind::Other, e.to_string()))?;
+    Ok(())
+}
+
+/// Dispatches to whichever backend is configured; a no-op when sync is
+/// disabled so callers can call this unconditionally.
+pub fn sync_session(config: &SyncConfig, session: &Session) -> io::Result<()> {
+    match config.backend {
+        SyncBackend::Disabled => Ok(()),
+        SyncBackend::WebDav => upload_webdav(config, session),
+        SyncBackend::GoogleSheets => append_google_sheets_row(config, session),
+    }
+}
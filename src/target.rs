@@ -0,0 +1,76 @@
+//! Target face definitions: ring radii and scoring geometry.
+
+use serde::{Deserialize, Serialize};
+
+/// A scoring target face, defined as a set of concentric ring radii in
+/// millimeters, ordered from the outermost (lowest value) ring inward.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TargetType {
+    pub name: String,
+    /// Radius in mm for each ring, indexed so `ring_radii_mm[0]` is the
+    /// 1-ring and the last entry is the 10-ring.
+    pub ring_radii_mm: Vec<f32>,
+    /// Radius in mm of the inner-ten / X-ring, used for X-count.
+    pub inner_ten_radius_mm: f32,
+}
+
+impl TargetType {
+    /// Score a point at the given distance (mm) from center as a decimal
+    /// value, and whether it counts as an X.
+    #[tracing::instrument(skip(self), fields(target = %self.name))]
+    pub fn score(&self, distance_mm: f32) -> (f32, bool) {
+        let is_x = distance_mm <= self.inner_ten_radius_mm;
+        for (i, radius) in self.ring_radii_mm.iter().enumerate() {
+            if distance_mm <= *radius {
+                let ring_value = (self.ring_radii_mm.len() - i) as f32;
+                tracing::trace!(distance_mm, ring_value, is_x, "shot scored");
+                return (ring_value, is_x);
+            }
+        }
+        tracing::trace!(distance_mm, "shot scored as a miss");
+        (0.0, false)
+    }
+}
+
+/// The standard ISSF 10m air pistol/rifle face, used as the default.
+pub fn issf_10m() -> TargetType {
+    TargetType {
+        name: "ISSF 10m".to_string(),
+        ring_radii_mm: vec![
+            77.5, 69.5, 61.5, 53.5, 45.5, 37.5, 29.5, 21.5, 13.5, 5.5,
+        ],
+        inner_ten_radius_mm: 2.75,
+    }
+}
+
+/// The ISSF 50m rifle face, scaled up for the longer distance.
+pub fn issf_50m_rifle() -> TargetType {
+    TargetType {
+        name: "ISSF 50m rifle".to_string(),
+        ring_radii_mm: vec![
+            302.5, 275.0, 247.5, 220.0, 192.5, 165.0, 137.5, 110.0, 82.5, 22.5,
+        ],
+        inner_ten_radius_mm: 5.0,
+    }
+}
+
+/// The ISSF 25m rapid-fire pistol face.
+pub fn issf_25m_pistol() -> TargetType {
+    TargetType {
+        name: "ISSF 25m pistol".to_string(),
+        ring_radii_mm: vec![
+            300.0, 275.0, 250.0, 225.0, 200.0, 150.0, 100.0, 75.0, 50.0, 25.0,
+        ],
+        inner_ten_radius_mm: 12.5,
+    }
+}
+
+/// Every built-in target face, for a preset picker in the UI or API.
+pub fn presets() -> Vec<TargetType> {
+    vec![issf_10m(), issf_50m_rifle(), issf_25m_pistol()]
+}
+
+/// Looks up a built-in preset by its `name`, case-insensitively.
+pub fn by_name(name: &str) -> Option<TargetType> {
+    presets().into_iter().find(|t| t.name.eq_ignore_ascii_case(name))
+}
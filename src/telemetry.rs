@@ -0,0 +1,75 @@
+//! Structured logging setup. Installs a `tracing` subscriber that both
+//! prints to stderr (for a terminal-attached range officer) and keeps a
+//! bounded ring buffer of recent formatted lines in memory, so
+//! [`crate::export::diagnostics`] can bundle "what just happened" without
+//! the caller needing to manage a log file on disk.
+
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+
+use tracing_subscriber::layer::SubscriberExt;
+use tracing_subscriber::Layer;
+
+/// How many recent formatted log lines to keep for diagnostic export.
+const RING_BUFFER_CAPACITY: usize = 500;
+
+static RING_BUFFER: Mutex<Option<Arc<Mutex<VecDeque<String>>>>> = Mutex::new(None);
+
+/// Installs the global `tracing` subscriber. Call once at startup, before
+/// any `tracing::info!`/`warn!`/`error!` calls are expected to be captured.
+pub fn init() {
+    let buffer = Arc::new(Mutex::new(VecDeque::with_capacity(RING_BUFFER_CAPACITY)));
+    *RING_BUFFER.lock().unwrap() = Some(buffer.clone());
+
+    let subscriber = tracing_subscriber::registry()
+        .with(tracing_subscriber::fmt::layer())
+        .with(RingBufferLayer { buffer });
+    if tracing::subscriber::set_global_default(subscriber).is_err() {
+        // Already installed (e.g. a second `init()` call in tests); not fatal.
+    }
+}
+
+/// Returns a snapshot of the most recent log lines, oldest first.
+pub fn recent_logs() -> Vec<String> {
+    RING_BUFFER
+        .lock()
+        .unwrap()
+        .as_ref()
+        .map(|buffer| buffer.lock().unwrap().iter().cloned().collect())
+        .unwrap_or_default()
+}
+
+/// A minimal `tracing_subscriber::Layer` that formats each event as a
+/// single line and pushes it into the shared ring buffer, evicting the
+/// oldest line once `RING_BUFFER_CAPACITY` is exceeded.
+struct RingBufferLayer {
+    buffer: Arc<Mutex<VecDeque<String>>>,
+}
+
+impl<S: tracing::Subscriber> Layer<S> for RingBufferLayer {
+    fn on_event(&self, event: &tracing::Event<'_>, _ctx: tracing_subscriber::layer::Context<'_, S>) {
+        let mut line = format!("{} ", event.metadata().level());
+        line.push_str(event.metadata().target());
+        line.push_str(": ");
+        let mut visitor = MessageVisitor(&mut line);
+        event.record(&mut visitor);
+
+        let mut buffer = self.buffer.lock().unwrap();
+        if buffer.len() == RING_BUFFER_CAPACITY {
+            buffer.pop_front();
+        }
+        buffer.push_back(line);
+    }
+}
+
+struct MessageVisitor<'a>(&'a mut String);
+
+impl tracing::field::Visit for MessageVisitor<'_> {
+    fn record_debug(&mut self, field: &tracing::field::Field, value: &dyn std::fmt::Debug) {
+        if field.name() == "message" {
+            self.0.push_str(&format!("{value:?}"));
+        } else {
+            self.0.push_str(&format!("{}={:?} ", field.name(), value));
+        }
+    }
+}
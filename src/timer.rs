@@ -0,0 +1,99 @@
+//! Match countdown timer: preparation phase followed by the match itself,
+//! with warnings as time runs low.
+
+use std::time::{Duration, Instant};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MatchPhase {
+    Preparation,
+    Match,
+    Expired,
+}
+
+/// Configuration for a match timer, set once before starting.
+#[derive(Debug, Clone, Copy)]
+pub struct TimerConfig {
+    pub preparation: Duration,
+    pub match_time: Duration,
+    /// Stop accepting new shots automatically once the match time expires.
+    pub stop_on_expiry: bool,
+}
+
+impl Default for TimerConfig {
+    fn default() -> Self {
+        Self {
+            preparation: Duration::from_secs(10 * 60),
+            match_time: Duration::from_secs(75 * 60),
+            stop_on_expiry: false,
+        }
+    }
+}
+
+/// A running (or stopped) match timer.
+pub struct MatchTimer {
+    config: TimerConfig,
+    phase: MatchPhase,
+    phase_started_at: Instant,
+    warned_10: bool,
+    warned_5: bool,
+}
+
+impl MatchTimer {
+    pub fn new(config: TimerConfig) -> Self {
+        Self {
+            config,
+            phase: MatchPhase::Preparation,
+            phase_started_at: Instant::now(),
+            warned_10: false,
+            warned_5: false,
+        }
+    }
+
+    pub fn phase(&self) -> MatchPhase {
+        self.phase
+    }
+
+    /// Time left in the current phase, zero once expired.
+    pub fn remaining(&self) -> Duration {
+        let total = match self.phase {
+            MatchPhase::Preparation => self.config.preparation,
+            MatchPhase::Match | MatchPhase::Expired => self.config.match_time,
+        };
+        total.saturating_sub(self.phase_started_at.elapsed())
+    }
+
+    /// Should be called once per frame; advances phases and returns any
+    /// warning that newly became due this tick.
+    pub fn tick(&mut self) -> Option<&'static str> {
+        if self.phase == MatchPhase::Preparation && self.remaining().is_zero() {
+            self.phase = MatchPhase::Match;
+            self.phase_started_at = Instant::now();
+            self.warned_10 = false;
+            self.warned_5 = false;
+            return Some("Preparation time over — match started");
+        }
+
+        if self.phase == MatchPhase::Match {
+            let remaining = self.remaining();
+            if remaining.is_zero() {
+                self.phase = MatchPhase::Expired;
+                return Some("Match time expired");
+            }
+            if !self.warned_10 && remaining <= Duration::from_secs(10 * 60) {
+                self.warned_10 = true;
+                return Some("10 minutes remaining");
+            }
+            if !self.warned_5 && remaining <= Duration::from_secs(5 * 60) {
+                self.warned_5 = true;
+                return Some("5 minutes remaining");
+            }
+        }
+        None
+    }
+
+    /// Whether new shots should currently be rejected because time expired
+    /// and the config asks to stop accepting shots automatically.
+    pub fn shots_locked(&self) -> bool {
+        self.config.stop_on_expiry && self.phase == MatchPhase::Expired
+    }
+}
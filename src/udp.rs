@@ -0,0 +1,62 @@
+//! Broadcasts compact shot packets over UDP on the local network, so
+//! other operator stations and a master scoreboard can aggregate lanes
+//! passively without any handshake or connection state.
+//!
+//! Packet format (20 bytes, little-endian, all multi-byte fields
+//! fixed-width so any listener can decode it without a schema):
+//!
+//! | offset | size | field         |
+//! |--------|------|---------------|
+//! | 0      | 4    | magic `"PSU1"`|
+//! | 4      | 2    | lane (u16)    |
+//! | 6      | 2    | shot no (u16) |
+//! | 8      | 4    | value (f32)   |
+//! | 12     | 4    | x_mm (f32)    |
+//! | 16     | 4    | y_mm (f32)    |
+//! | 20     | 1    | is_x (0/1)    |
+
+use std::net::UdpSocket;
+
+use crate::session::Shot;
+
+const MAGIC: &[u8; 4] = b"PSU1";
+pub const PACKET_LEN: usize = 21;
+
+pub struct UdpBroadcaster {
+    socket: UdpSocket,
+    broadcast_addr: String,
+    lane: u16,
+}
+
+impl UdpBroadcaster {
+    /// Binds an ephemeral local socket and enables broadcast, sending
+    /// every packet to `broadcast_addr` (e.g. `"255.255.255.255:8790"`)
+    /// tagged with this station's `lane` number.
+    pub fn start(broadcast_addr: &str, lane: u16) -> std::io::Result<Self> {
+        let socket = UdpSocket::bind("0.0.0.0:0")?;
+        socket.set_broadcast(true)?;
+        Ok(Self { socket, broadcast_addr: broadcast_addr.to_string(), lane })
+    }
+
+    pub fn broadcast_addr(&self) -> &str {
+        &self.broadcast_addr
+    }
+
+    pub fn lane(&self) -> u16 {
+        self.lane
+    }
+
+    /// Encodes and sends one packet; send errors (e.g. no listener,
+    /// unreachable subnet) are ignored since UDP delivery is best-effort.
+    pub fn broadcast_shot(&self, shot: &Shot) {
+        let mut packet = [0u8; PACKET_LEN];
+        packet[0..4].copy_from_slice(MAGIC);
+        packet[4..6].copy_from_slice(&self.lane.to_le_bytes());
+        packet[6..8].copy_from_slice(&(shot.number as u16).to_le_bytes());
+        packet[8..12].copy_from_slice(&shot.value.to_le_bytes());
+        packet[12..16].copy_from_slice(&shot.x_mm.to_le_bytes());
+        packet[16..20].copy_from_slice(&shot.y_mm.to_le_bytes());
+        packet[20] = shot.is_x as u8;
+        let _ = self.socket.send_to(&packet, &self.broadcast_addr);
+    }
+}
@@ -0,0 +1,55 @@
+//! Two-point known-distance calibration: click two points a known
+//! distance apart and type the real distance to compute `pixels_per_mm`,
+//! as a quick alternative to the full wizard or the px/mm slider.
+
+use crate::calibration::ScoringConfig;
+
+#[derive(Default)]
+pub struct TwoPointCalibrateTool {
+    pub active: bool,
+    point_a: Option<(f32, f32)>,
+    point_b: Option<(f32, f32)>,
+    pub known_distance_mm: f32,
+}
+
+impl TwoPointCalibrateTool {
+    /// Feeds a click captured elsewhere (from `ImageView::last_click_px`)
+    /// into the tool while it's active.
+    pub fn record_click(&mut self, pos: (f32, f32)) {
+        if !self.active {
+            return;
+        }
+        if self.point_a.is_none() {
+            self.point_a = Some(pos);
+        } else if self.point_b.is_none() {
+            self.point_b = Some(pos);
+        } else {
+            self.point_a = Some(pos);
+            self.point_b = None;
+        }
+    }
+
+    /// Draws the tool's controls; returns an updated calibration once
+    /// both points are set and the user confirms.
+    pub fn show(&mut self, ui: &mut egui::Ui, current: ScoringConfig) -> Option<ScoringConfig> {
+        ui.checkbox(&mut self.active, "Two-point calibrate (click image)");
+        if !self.active {
+            return None;
+        }
+        ui.add(egui::Slider::new(&mut self.known_distance_mm, 1.0..=500.0).text("Known distance (mm)"));
+        ui.label(format!("A: {:?}  B: {:?}", self.point_a, self.point_b));
+
+        let (a, b) = (self.point_a?, self.point_b?);
+        let pixel_dist = ((a.0 - b.0).powi(2) + (a.1 - b.1).powi(2)).sqrt();
+        if pixel_dist <= 0.0 || self.known_distance_mm <= 0.0 {
+            return None;
+        }
+        if ui.button("Apply calibration").clicked() {
+            return Some(ScoringConfig {
+                pixels_per_mm: pixel_dist / self.known_distance_mm,
+                ..current
+            });
+        }
+        None
+    }
+}
@@ -0,0 +1,102 @@
+//! Step-by-step calibration wizard: select target type → frame the card →
+//! confirm detected rings or click two known points → verify with an
+//! overlay check. Writes the resulting `ScoringConfig` when finished.
+
+use crate::calibration::ScoringConfig;
+use crate::target::TargetType;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Step {
+    SelectTarget,
+    FrameCard,
+    ClickTwoPoints,
+    Verify,
+}
+
+pub struct CalibrationWizard {
+    step: Step,
+    working: ScoringConfig,
+    click_a: Option<(f32, f32)>,
+    click_b: Option<(f32, f32)>,
+    known_distance_mm: f32,
+}
+
+impl Default for CalibrationWizard {
+    fn default() -> Self {
+        Self {
+            step: Step::SelectTarget,
+            working: ScoringConfig::default(),
+            click_a: None,
+            click_b: None,
+            known_distance_mm: 100.0,
+        }
+    }
+}
+
+impl CalibrationWizard {
+    /// Draws the current step. Returns `Some(config)` once the user
+    /// finishes the wizard on the verify step.
+    pub fn show(&mut self, ui: &mut egui::Ui, target: &TargetType) -> Option<ScoringConfig> {
+        ui.label(format!("Target: {}", target.name));
+
+        match self.step {
+            Step::SelectTarget => {
+                ui.label("Step 1: confirm target type, then frame the card in view.");
+                if ui.button("Next").clicked() {
+                    self.step = Step::FrameCard;
+                }
+            }
+            Step::FrameCard => {
+                ui.label("Step 2: adjust crop/zoom until the whole card is visible, then continue.");
+                if ui.button("Next").clicked() {
+                    self.step = Step::ClickTwoPoints;
+                }
+            }
+            Step::ClickTwoPoints => {
+                ui.label("Step 3: click two known points on the card and enter the real distance.");
+                ui.add(
+                    egui::Slider::new(&mut self.known_distance_mm, 1.0..=500.0)
+                        .text("Known distance (mm)"),
+                );
+                ui.label(format!("Point A: {:?}", self.click_a));
+                ui.label(format!("Point B: {:?}", self.click_b));
+                if let (Some(a), Some(b)) = (self.click_a, self.click_b) {
+                    let pixel_dist = ((a.0 - b.0).powi(2) + (a.1 - b.1).powi(2)).sqrt();
+                    if pixel_dist > 0.0 {
+                        self.working.pixels_per_mm = pixel_dist / self.known_distance_mm;
+                        self.working.center_px = ((a.0 + b.0) / 2.0, (a.1 + b.1) / 2.0);
+                    }
+                    if ui.button("Next").clicked() {
+                        self.step = Step::Verify;
+                    }
+                }
+            }
+            Step::Verify => {
+                ui.label("Step 4: verify the overlay lines up with the printed rings.");
+                ui.label(format!(
+                    "center = {:?}, {:.3} px/mm",
+                    self.working.center_px, self.working.pixels_per_mm
+                ));
+                if ui.button("Finish").clicked() {
+                    return Some(self.working);
+                }
+                if ui.button("Back").clicked() {
+                    self.step = Step::ClickTwoPoints;
+                }
+            }
+        }
+        None
+    }
+
+    /// Records a click on the framed image while on the two-point step.
+    pub fn record_click(&mut self, pos: (f32, f32)) {
+        if self.step != Step::ClickTwoPoints {
+            return;
+        }
+        if self.click_a.is_none() {
+            self.click_a = Some(pos);
+        } else {
+            self.click_b = Some(pos);
+        }
+    }
+}
@@ -0,0 +1,73 @@
+//! Side-by-side (or overlaid) comparison of two saved sessions — e.g. this
+//! week's training against last week's.
+
+use egui_plot::{Plot, Points};
+
+use crate::session::Session;
+
+#[derive(Default)]
+pub struct CompareView {
+    pub left: Option<Session>,
+    pub right: Option<Session>,
+    pub overlay: bool,
+}
+
+impl CompareView {
+    pub fn show(&mut self, ui: &mut egui::Ui) {
+        ui.horizontal(|ui| {
+            if ui.button("Load session A…").clicked() {
+                self.left = pick_and_load();
+            }
+            if ui.button("Load session B…").clicked() {
+                self.right = pick_and_load();
+            }
+            ui.checkbox(&mut self.overlay, "Overlay");
+        });
+
+        if self.overlay {
+            Plot::new("compare_overlay").height(300.0).show(ui, |plot_ui| {
+                if let Some(session) = &self.left {
+                    plot_ui.points(session_points(session, egui::Color32::from_rgb(0, 150, 255)));
+                }
+                if let Some(session) = &self.right {
+                    plot_ui.points(session_points(session, egui::Color32::from_rgb(255, 120, 0)));
+                }
+            });
+        } else {
+            ui.columns(2, |cols| {
+                show_column(&mut cols[0], "Session A", &self.left);
+                show_column(&mut cols[1], "Session B", &self.right);
+            });
+        }
+    }
+}
+
+fn show_column(ui: &mut egui::Ui, label: &str, session: &Option<Session>) {
+    ui.label(egui::RichText::new(label).strong());
+    let Some(session) = session else {
+        ui.label("(not loaded)");
+        return;
+    };
+    ui.label(format!(
+        "{}  total {:.1}  X {}",
+        session.shooter.name,
+        session.total(),
+        session.x_count()
+    ));
+    Plot::new(format!("compare_{label}")).height(250.0).show(ui, |plot_ui| {
+        plot_ui.points(session_points(session, egui::Color32::from_rgb(0, 150, 255)));
+    });
+}
+
+fn session_points(session: &Session, color: egui::Color32) -> Points {
+    let pts: Vec<[f64; 2]> = session
+        .all_shots()
+        .map(|s| [s.x_mm as f64, s.y_mm as f64])
+        .collect();
+    Points::new(pts).radius(3.0).color(color)
+}
+
+fn pick_and_load() -> Option<Session> {
+    let path = rfd::FileDialog::new().add_filter("JSON", &["json"]).pick_file()?;
+    Session::load_json(&path).ok()
+}
@@ -0,0 +1,31 @@
+//! Live grayscale histogram of the cropped ROI, with the current
+//! threshold marked as a draggable vertical line.
+
+use egui_plot::{Bar, BarChart, Plot, VLine};
+use image::GrayImage;
+
+/// Computes a 256-bucket grayscale histogram for `roi`.
+pub fn compute(roi: &GrayImage) -> [u32; 256] {
+    let mut buckets = [0u32; 256];
+    for pixel in roi.pixels() {
+        buckets[pixel.0[0] as usize] += 1;
+    }
+    buckets
+}
+
+/// Shows the histogram with `threshold` as a draggable line; returns the
+/// updated threshold if the user dragged it.
+pub fn show(ui: &mut egui::Ui, histogram: &[u32; 256], threshold: &mut u8) {
+    let bars: Vec<Bar> = histogram
+        .iter()
+        .enumerate()
+        .map(|(v, count)| Bar::new(v as f64, *count as f64))
+        .collect();
+
+    Plot::new("threshold_histogram").height(140.0).show(ui, |plot_ui| {
+        plot_ui.bar_chart(BarChart::new(bars));
+        plot_ui.vline(VLine::new(*threshold as f64).name("threshold"));
+    });
+
+    ui.add(egui::Slider::new(threshold, 0..=255).text("Threshold"));
+}
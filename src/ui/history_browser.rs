@@ -0,0 +1,80 @@
+//! History screen: lists sessions saved to the on-disk history store,
+//! with filters and aggregate statistics across the filtered set.
+
+use crate::history::{self, HistoryEntry};
+use crate::session::Session;
+
+#[derive(Default)]
+pub struct HistoryBrowser {
+    entries: Vec<HistoryEntry>,
+    loaded: bool,
+    filter_shooter: String,
+    filter_discipline: String,
+    /// Inclusive range of Unix seconds; empty string means unbounded.
+    filter_from: String,
+    filter_to: String,
+}
+
+impl HistoryBrowser {
+    /// Returns the session the user asked to open, read-only, if any.
+    pub fn show(&mut self, ui: &mut egui::Ui) -> Option<Session> {
+        if !self.loaded {
+            self.entries = history::list_history();
+            self.loaded = true;
+        }
+        if ui.button("Refresh").clicked() {
+            self.entries = history::list_history();
+        }
+
+        ui.horizontal(|ui| {
+            ui.label("Shooter:");
+            ui.text_edit_singleline(&mut self.filter_shooter);
+            ui.label("Discipline:");
+            ui.text_edit_singleline(&mut self.filter_discipline);
+            ui.label("From (unix secs):");
+            ui.text_edit_singleline(&mut self.filter_from);
+            ui.label("To:");
+            ui.text_edit_singleline(&mut self.filter_to);
+        });
+
+        let from: Option<u64> = self.filter_from.parse().ok();
+        let to: Option<u64> = self.filter_to.parse().ok();
+        let filtered: Vec<&HistoryEntry> = self
+            .entries
+            .iter()
+            .filter(|e| self.filter_shooter.is_empty() || e.shooter.contains(&self.filter_shooter))
+            .filter(|e| {
+                self.filter_discipline.is_empty() || e.discipline.contains(&self.filter_discipline)
+            })
+            .filter(|e| from.map_or(true, |from| e.started_at_secs >= from))
+            .filter(|e| to.map_or(true, |to| e.started_at_secs <= to))
+            .collect();
+
+        ui.separator();
+        let count = filtered.len();
+        let total_sum: f32 = filtered.iter().map(|e| e.total).sum();
+        let x_sum: usize = filtered.iter().map(|e| e.x_count).sum();
+        let average = if count > 0 { total_sum / count as f32 } else { 0.0 };
+        ui.label(format!(
+            "{count} session(s)   average total {average:.1}   combined X-count {x_sum}"
+        ));
+        ui.separator();
+
+        let mut to_open = None;
+        egui::ScrollArea::vertical().show(ui, |ui| {
+            for entry in &filtered {
+                ui.horizontal(|ui| {
+                    ui.label(format!(
+                        "{}  {}  {}  total {:.1}  X:{}",
+                        entry.started_at_secs, entry.shooter, entry.discipline, entry.total, entry.x_count
+                    ));
+                    if ui.button("Open").clicked() {
+                        to_open = Some(entry.path.clone());
+                    }
+                });
+            }
+        });
+
+        to_open.and_then(|path| Session::load_json(&path).ok())
+    }
+}
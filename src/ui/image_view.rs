@@ -0,0 +1,449 @@
+//! Central image view: displays the current frame with mouse-wheel zoom
+//! and drag-to-pan, paints scoring overlays on top, and reports live
+//! coordinates under the cursor.
+
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+use image::RgbImage;
+use serde::{Deserialize, Serialize};
+
+use crate::calibration::ScoringConfig;
+use crate::overlay::ZonePalette;
+use crate::session::Session;
+use crate::target::TargetType;
+
+/// Everything the view needs to place overlays correctly; bundled since
+/// the parameter list kept growing with each overlay feature.
+pub struct ImageViewParams<'a> {
+    pub calibration: ScoringConfig,
+    pub target: &'a TargetType,
+    pub session: &'a Session,
+    pub palette: &'a ZonePalette,
+    /// Gauge (caliber) diameter in mm, drawn around the selected shot.
+    pub gauge_diameter_mm: f32,
+}
+
+const MIN_ZOOM: f32 = 0.1;
+const MAX_ZOOM: f32 = 16.0;
+const CROP_HANDLE_SIZE: f32 = 10.0;
+const CROP_MIN_SIZE: f32 = 10.0;
+
+/// A region of interest in image-pixel coordinates, edited by dragging
+/// corner handles directly on the preview instead of four sliders.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct CropRect {
+    pub x: f32,
+    pub y: f32,
+    pub w: f32,
+    pub h: f32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum CropHandle {
+    TopLeft,
+    TopRight,
+    BottomLeft,
+    BottomRight,
+}
+
+pub struct ImageView {
+    texture: Option<egui::TextureHandle>,
+    last_frame_size: (u32, u32),
+    /// Cheap hash of the last uploaded frame's pixels, so an unchanged
+    /// frame (e.g. a frozen preview or static-image review) skips both
+    /// the `ColorImage` allocation and the GPU upload entirely.
+    last_frame_hash: u64,
+    pub selected_shot: Option<usize>,
+    /// Last raw pixel position clicked in the image, regardless of
+    /// whether it landed on a shot marker — used by tools like the
+    /// two-point calibration and measurement tools.
+    pub last_click_px: Option<(f32, f32)>,
+    last_shot_count: usize,
+    pulse_started_at: Option<std::time::Instant>,
+    /// Screen pixels per image pixel. Reset to the fit-to-window scale
+    /// whenever a differently-sized frame arrives; otherwise adjusted by
+    /// mouse-wheel zoom.
+    zoom: f32,
+    /// Image-pixel coordinate currently shown at the top-left corner of
+    /// the view, i.e. the pan offset.
+    pan: egui::Vec2,
+    /// When true, an ROI rectangle with draggable corner handles is drawn
+    /// over the image, used for e.g. selecting the histogram region.
+    pub crop_enabled: bool,
+    pub crop: Option<CropRect>,
+}
+
+impl Default for ImageView {
+    fn default() -> Self {
+        Self {
+            texture: None,
+            last_frame_size: (0, 0),
+            last_frame_hash: 0,
+            selected_shot: None,
+            last_click_px: None,
+            last_shot_count: 0,
+            pulse_started_at: None,
+            zoom: 1.0,
+            pan: egui::Vec2::ZERO,
+            crop_enabled: false,
+            crop: None,
+        }
+    }
+}
+
+const PULSE_DURATION: std::time::Duration = std::time::Duration::from_millis(1500);
+
+impl ImageView {
+    pub fn show(&mut self, ui: &mut egui::Ui, frame: Option<&RgbImage>, params: ImageViewParams) {
+        puffin::profile_scope!("paint");
+        let Some(frame) = frame else {
+            ui.label("No frame loaded.");
+            return;
+        };
+
+        let available = ui.available_size();
+
+        let resized = self.texture.is_none() || self.last_frame_size != frame.dimensions();
+        let frame_hash = hash_frame(frame);
+        if resized || frame_hash != self.last_frame_hash {
+            let size = [frame.width() as usize, frame.height() as usize];
+            let color_image = egui::ColorImage::from_rgb(size, frame.as_raw());
+            match &mut self.texture {
+                // Same dimensions: update the existing GPU texture in place
+                // instead of allocating a new one.
+                Some(texture) if !resized => texture.set(color_image, Default::default()),
+                _ => {
+                    self.texture =
+                        Some(ui.ctx().load_texture("image_view", color_image, Default::default()));
+                }
+            }
+            self.last_frame_size = frame.dimensions();
+            self.last_frame_hash = frame_hash;
+            if resized {
+                self.zoom = self.fit_scale(frame, available);
+                self.pan = egui::Vec2::ZERO;
+            }
+        }
+        let texture = self.texture.as_ref().unwrap();
+
+        let (response, painter) =
+            ui.allocate_painter(available, egui::Sense::click_and_drag());
+
+        if let Some(pointer) = response.hover_pos() {
+            let scroll = ui.input(|i| i.raw_scroll_delta.y);
+            if scroll != 0.0 {
+                let old_zoom = self.zoom;
+                let new_zoom = (old_zoom * (1.0 + scroll * 0.001)).clamp(MIN_ZOOM, MAX_ZOOM);
+                // Keep the image point under the cursor fixed while zooming.
+                let cursor_img = self.pan + (pointer - response.rect.min) / old_zoom;
+                self.zoom = new_zoom;
+                self.pan = cursor_img - (pointer - response.rect.min) / new_zoom;
+            }
+        }
+        if response.dragged() {
+            self.pan -= response.drag_delta() / self.zoom;
+        }
+        self.clamp_pan(frame, available);
+
+        let to_screen = |px: (f32, f32)| -> egui::Pos2 {
+            response.rect.min + (egui::vec2(px.0, px.1) - self.pan) * self.zoom
+        };
+
+        let image_rect = egui::Rect::from_min_size(
+            to_screen((0.0, 0.0)),
+            egui::vec2(frame.width() as f32, frame.height() as f32) * self.zoom,
+        );
+        painter.image(
+            texture.id(),
+            image_rect,
+            egui::Rect::from_min_max(egui::pos2(0.0, 0.0), egui::pos2(1.0, 1.0)),
+            egui::Color32::WHITE,
+        );
+
+        let fully_visible = image_rect.width() <= available.x + 0.5
+            && image_rect.height() <= available.y + 0.5;
+        if !fully_visible {
+            self.show_minimap(ui, texture, frame, available, response.rect);
+        }
+
+        let mm_to_px = |x_mm: f32, y_mm: f32| -> (f32, f32) {
+            let (rx, ry) = crate::overlay::rotate_mm(x_mm, y_mm, params.calibration.rotation_deg);
+            (
+                params.calibration.center_px.0 + rx * params.calibration.pixels_per_mm,
+                params.calibration.center_px.1 - ry * params.calibration.pixels_per_mm,
+            )
+        };
+
+        for (color, radius_mm) in crate::overlay::ring_colors(params.target, params.palette) {
+            let center = to_screen(params.calibration.center_px);
+            painter.circle_stroke(
+                center,
+                radius_mm * params.calibration.pixels_per_mm * self.zoom,
+                egui::Stroke::new(1.5, egui::Color32::from_rgb(color[0], color[1], color[2])),
+            );
+        }
+        let total_shots = params.session.all_shots().count();
+        if total_shots > self.last_shot_count {
+            self.pulse_started_at = Some(std::time::Instant::now());
+        }
+        self.last_shot_count = total_shots;
+
+        for (i, shot) in params.session.all_shots().enumerate() {
+            let px = mm_to_px(shot.x_mm, shot.y_mm);
+            let color = params.palette.color_for_ring(shot.value);
+            let mut radius = 4.0;
+            if i + 1 == total_shots {
+                if let Some(pulse) = self.newest_shot_pulse() {
+                    radius += pulse * 6.0;
+                    ui.ctx().request_repaint();
+                }
+            }
+            painter.circle_filled(
+                to_screen(px),
+                radius,
+                egui::Color32::from_rgb(color[0], color[1], color[2]),
+            );
+            if self.selected_shot == Some(i) {
+                painter.circle_stroke(
+                    to_screen(px),
+                    params.gauge_diameter_mm / 2.0 * params.calibration.pixels_per_mm * self.zoom,
+                    egui::Stroke::new(2.0, egui::Color32::WHITE),
+                );
+            }
+        }
+
+        if self.crop_enabled {
+            self.show_crop_handles(ui, &painter, &response, frame);
+        }
+
+        if response.clicked() {
+            if let Some(pos) = response.interact_pointer_pos() {
+                self.selected_shot = self.closest_shot(pos, response.rect.min, &params);
+                let local = self.pan + (pos - response.rect.min) / self.zoom;
+                self.last_click_px = Some((local.x, local.y));
+            }
+        }
+
+        if let Some(pointer) = response.hover_pos() {
+            let local = self.pan + (pointer - response.rect.min) / self.zoom;
+            let px = local.x;
+            let py = local.y;
+
+            let dx_mm = (px - params.calibration.center_px.0) / params.calibration.pixels_per_mm;
+            let dy_mm = (params.calibration.center_px.1 - py) / params.calibration.pixels_per_mm;
+            let (x_mm, y_mm) = crate::overlay::rotate_mm(dx_mm, dy_mm, -params.calibration.rotation_deg);
+            let distance_mm = (x_mm * x_mm + y_mm * y_mm).sqrt();
+            let (value, is_x) = params.target.score(distance_mm);
+
+            egui::show_tooltip(ui.ctx(), ui.layer_id(), egui::Id::new("image_view_tooltip"), |ui| {
+                ui.label(format!(
+                    "({x_mm:+.1}, {y_mm:+.1}) mm   dist {distance_mm:.1} mm   value {value:.1}{}",
+                    if is_x { " (X)" } else { "" }
+                ));
+            });
+        }
+    }
+
+    /// Returns the current crop rectangle as integer pixel bounds, clamped
+    /// to the given frame size, for use with `image::imageops::crop_imm`.
+    pub fn crop_region(&self, frame: &RgbImage) -> Option<(u32, u32, u32, u32)> {
+        let rect = self.crop?;
+        if !self.crop_enabled {
+            return None;
+        }
+        let x = rect.x.clamp(0.0, frame.width() as f32) as u32;
+        let y = rect.y.clamp(0.0, frame.height() as f32) as u32;
+        let w = rect.w.min(frame.width() as f32 - x as f32).max(1.0) as u32;
+        let h = rect.h.min(frame.height() as f32 - y as f32).max(1.0) as u32;
+        Some((x, y, w, h))
+    }
+
+    /// Draws the crop rectangle and its four draggable corner handles,
+    /// initializing the rect to the full frame on first use.
+    fn show_crop_handles(
+        &mut self,
+        ui: &mut egui::Ui,
+        painter: &egui::Painter,
+        response: &egui::Response,
+        frame: &RgbImage,
+    ) {
+        let (frame_w, frame_h) = (frame.width() as f32, frame.height() as f32);
+        let rect = self.crop.get_or_insert(CropRect { x: 0.0, y: 0.0, w: frame_w, h: frame_h });
+
+        let to_screen =
+            |px: (f32, f32)| response.rect.min + (egui::vec2(px.0, px.1) - self.pan) * self.zoom;
+        let screen_rect = egui::Rect::from_min_max(
+            to_screen((rect.x, rect.y)),
+            to_screen((rect.x + rect.w, rect.y + rect.h)),
+        );
+        painter.rect_stroke(screen_rect, 0.0, egui::Stroke::new(2.0, egui::Color32::YELLOW));
+        painter.text(
+            screen_rect.min + egui::vec2(4.0, -18.0),
+            egui::Align2::LEFT_BOTTOM,
+            format!("{:.0},{:.0}  {:.0}x{:.0}", rect.x, rect.y, rect.w, rect.h),
+            egui::FontId::monospace(12.0),
+            egui::Color32::YELLOW,
+        );
+
+        let corners = [
+            (CropHandle::TopLeft, screen_rect.left_top()),
+            (CropHandle::TopRight, screen_rect.right_top()),
+            (CropHandle::BottomLeft, screen_rect.left_bottom()),
+            (CropHandle::BottomRight, screen_rect.right_bottom()),
+        ];
+        for (handle, pos) in corners {
+            let handle_rect = egui::Rect::from_center_size(pos, egui::Vec2::splat(CROP_HANDLE_SIZE));
+            let id = ui.make_persistent_id(("crop_handle", handle));
+            let handle_response = ui.interact(handle_rect, id, egui::Sense::drag());
+            painter.rect_filled(handle_rect, 2.0, egui::Color32::YELLOW);
+
+            if handle_response.dragged() {
+                let delta = handle_response.drag_delta() / self.zoom;
+                match handle {
+                    CropHandle::TopLeft => {
+                        rect.x += delta.x;
+                        rect.y += delta.y;
+                        rect.w -= delta.x;
+                        rect.h -= delta.y;
+                    }
+                    CropHandle::TopRight => {
+                        rect.y += delta.y;
+                        rect.w += delta.x;
+                        rect.h -= delta.y;
+                    }
+                    CropHandle::BottomLeft => {
+                        rect.x += delta.x;
+                        rect.w -= delta.x;
+                        rect.h += delta.y;
+                    }
+                    CropHandle::BottomRight => {
+                        rect.w += delta.x;
+                        rect.h += delta.y;
+                    }
+                }
+                rect.x = rect.x.clamp(0.0, frame_w - CROP_MIN_SIZE);
+                rect.y = rect.y.clamp(0.0, frame_h - CROP_MIN_SIZE);
+                rect.w = rect.w.clamp(CROP_MIN_SIZE, frame_w - rect.x);
+                rect.h = rect.h.clamp(CROP_MIN_SIZE, frame_h - rect.y);
+            }
+        }
+    }
+
+    /// Resets zoom and pan so the whole frame is fit to the current view.
+    pub fn reset_zoom(&mut self, frame: &RgbImage, available: egui::Vec2) {
+        self.zoom = self.fit_scale(frame, available);
+        self.pan = egui::Vec2::ZERO;
+    }
+
+    fn fit_scale(&self, frame: &RgbImage, available: egui::Vec2) -> f32 {
+        (available.x / frame.width() as f32)
+            .min(available.y / frame.height() as f32)
+            .min(1.0)
+            .max(MIN_ZOOM)
+    }
+
+    /// Keeps the pan offset from drifting so far that the image leaves
+    /// the view entirely.
+    fn clamp_pan(&mut self, frame: &RgbImage, available: egui::Vec2) {
+        let visible_w = available.x / self.zoom;
+        let visible_h = available.y / self.zoom;
+        let max_x = (frame.width() as f32 - visible_w).max(0.0);
+        let max_y = (frame.height() as f32 - visible_h).max(0.0);
+        self.pan.x = self.pan.x.clamp(0.0, max_x);
+        self.pan.y = self.pan.y.clamp(0.0, max_y);
+    }
+
+    /// Public counterpart of `newest_shot_pulse`, used by the "Last Shot"
+    /// readout to briefly enlarge alongside the marker's pulse.
+    pub fn last_shot_readout_boost(&self) -> f32 {
+        self.newest_shot_pulse().unwrap_or(0.0)
+    }
+
+    /// Returns a fading pulse intensity (1.0 → 0.0) for the newest shot
+    /// marker while it's within `PULSE_DURATION` of being scored.
+    fn newest_shot_pulse(&self) -> Option<f32> {
+        let started = self.pulse_started_at?;
+        let elapsed = started.elapsed();
+        if elapsed >= PULSE_DURATION {
+            return None;
+        }
+        let t = elapsed.as_secs_f32() / PULSE_DURATION.as_secs_f32();
+        Some(((1.0 - t) * (t * std::f32::consts::TAU * 3.0).sin().abs()).max(0.0))
+    }
+
+    /// Draws a small thumbnail of the full frame with a rectangle marking
+    /// the currently visible viewport; clicking it pans there.
+    fn show_minimap(
+        &mut self,
+        ui: &mut egui::Ui,
+        texture: &egui::TextureHandle,
+        frame: &RgbImage,
+        available: egui::Vec2,
+        view_rect: egui::Rect,
+    ) {
+        let (w, h) = (frame.width() as f32, frame.height() as f32);
+        let minimap_size = egui::vec2(160.0, 160.0 * h / w);
+        egui::Area::new(egui::Id::new("image_view_minimap"))
+            .anchor(egui::Align2::RIGHT_BOTTOM, egui::vec2(-12.0, -12.0))
+            .show(ui.ctx(), |ui| {
+                egui::Frame::popup(ui.style()).show(ui, |ui| {
+                    let response =
+                        ui.add(egui::Image::new((texture.id(), minimap_size)).sense(egui::Sense::click()));
+                    let map_scale = minimap_size.x / w;
+                    let visible = egui::Rect::from_min_size(
+                        self.pan.to_pos2(),
+                        egui::vec2(available.x / self.zoom, available.y / self.zoom),
+                    );
+                    let rect_in_map = egui::Rect::from_min_size(
+                        response.rect.min + visible.min.to_vec2() * map_scale,
+                        visible.size() * map_scale,
+                    );
+                    ui.painter().rect_stroke(
+                        rect_in_map,
+                        0.0,
+                        egui::Stroke::new(1.5, egui::Color32::YELLOW),
+                    );
+                    if response.clicked() {
+                        if let Some(pos) = response.interact_pointer_pos() {
+                            let local = (pos - response.rect.min) / map_scale;
+                            self.pan = local - egui::vec2(available.x, available.y) / self.zoom / 2.0;
+                            let _ = view_rect;
+                        }
+                    }
+                });
+            });
+    }
+
+    fn closest_shot(
+        &self,
+        pointer: egui::Pos2,
+        origin: egui::Pos2,
+        params: &ImageViewParams,
+    ) -> Option<usize> {
+        params
+            .session
+            .all_shots()
+            .enumerate()
+            .map(|(i, shot)| {
+                let (rx, ry) =
+                    crate::overlay::rotate_mm(shot.x_mm, shot.y_mm, params.calibration.rotation_deg);
+                let px = params.calibration.center_px.0 + rx * params.calibration.pixels_per_mm;
+                let py = params.calibration.center_px.1 - ry * params.calibration.pixels_per_mm;
+                let screen = origin + (egui::vec2(px, py) - self.pan) * self.zoom;
+                (i, screen.distance(pointer))
+            })
+            .min_by(|a, b| a.1.total_cmp(&b.1))
+            .filter(|(_, dist)| *dist < 15.0)
+            .map(|(i, _)| i)
+    }
+}
+
+/// Cheap, non-cryptographic hash of the raw pixel buffer, used only to
+/// detect an unchanged frame — collisions would just cost a redundant
+/// upload, not a correctness bug.
+pub(crate) fn hash_frame(frame: &RgbImage) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    frame.as_raw().hash(&mut hasher);
+    hasher.finish()
+}
@@ -0,0 +1,43 @@
+//! Measure tool: click two points on the image and read back the
+//! distance in both pixels and the configured unit, using the current
+//! calibration. Handy for checking calibration or group spreads.
+
+use crate::units::UnitSettings;
+
+#[derive(Default)]
+pub struct MeasureTool {
+    pub active: bool,
+    point_a: Option<(f32, f32)>,
+    point_b: Option<(f32, f32)>,
+}
+
+impl MeasureTool {
+    pub fn record_click(&mut self, pos: (f32, f32)) {
+        if !self.active {
+            return;
+        }
+        if self.point_a.is_none() || self.point_b.is_some() {
+            self.point_a = Some(pos);
+            self.point_b = None;
+        } else {
+            self.point_b = Some(pos);
+        }
+    }
+
+    pub fn show(&mut self, ui: &mut egui::Ui, pixels_per_mm: f32, units: &UnitSettings) {
+        ui.checkbox(&mut self.active, "Measure tool (click two points)");
+        if !self.active {
+            return;
+        }
+        let (Some(a), Some(b)) = (self.point_a, self.point_b) else {
+            ui.label("Click two points on the image.");
+            return;
+        };
+        let pixel_dist = ((a.0 - b.0).powi(2) + (a.1 - b.1).powi(2)).sqrt();
+        let mm_dist = pixel_dist / pixels_per_mm;
+        ui.label(format!(
+            "Distance: {pixel_dist:.1} px  ({})",
+            crate::units::format_distance(mm_dist, units)
+        ));
+    }
+}
@@ -0,0 +1,17 @@
+//! Secondary UI panels, kept out of `app.rs` so `MyApp::update` stays a
+//! thin wiring layer.
+
+pub mod calibrate_two_point;
+pub mod calibration_wizard;
+pub mod compare;
+pub mod histogram;
+pub mod history_browser;
+pub mod image_view;
+pub mod measure_tool;
+pub mod replay;
+pub mod shot_list;
+pub mod status_bar;
+pub mod tabs;
+pub mod theme;
+pub mod touch_mode;
+pub mod trend_chart;
@@ -0,0 +1,82 @@
+//! Chronological replay of a session's shots — steps through shots one at
+//! a time with timestamps and running total, for coaching debriefs.
+
+use egui_plot::{Plot, Points};
+
+use crate::session::{Session, Shot};
+
+pub struct ReplayState {
+    pub cursor: usize,
+    pub playing: bool,
+    /// Seconds of real time per step while `playing`.
+    pub step_seconds: f32,
+    since_last_step: f32,
+}
+
+impl Default for ReplayState {
+    fn default() -> Self {
+        Self {
+            cursor: 0,
+            playing: false,
+            step_seconds: 1.0,
+            since_last_step: 0.0,
+        }
+    }
+}
+
+impl ReplayState {
+    pub fn show(&mut self, ui: &mut egui::Ui, session: &Session, dt: f32) {
+        let shots: Vec<&Shot> = session.all_shots().collect();
+        self.cursor = self.cursor.min(shots.len());
+
+        ui.horizontal(|ui| {
+            if ui.button("⏮").clicked() {
+                self.cursor = 0;
+            }
+            if ui.button("◀").clicked() {
+                self.cursor = self.cursor.saturating_sub(1);
+            }
+            let play_label = if self.playing { "⏸" } else { "▶" };
+            if ui.button(play_label).clicked() {
+                self.playing = !self.playing;
+            }
+            if ui.button("▶|").clicked() {
+                self.cursor = (self.cursor + 1).min(shots.len());
+            }
+            ui.add(egui::Slider::new(&mut self.step_seconds, 0.1..=5.0).text("sec/shot"));
+        });
+
+        if self.playing {
+            self.since_last_step += dt;
+            if self.since_last_step >= self.step_seconds {
+                self.since_last_step = 0.0;
+                if self.cursor < shots.len() {
+                    self.cursor += 1;
+                } else {
+                    self.playing = false;
+                }
+            }
+        }
+
+        let visible = &shots[..self.cursor];
+        let running_total: f32 = visible.iter().map(|s| s.value).sum();
+        ui.label(format!(
+            "Shot {}/{}   Running total: {:.1}",
+            self.cursor,
+            shots.len(),
+            running_total
+        ));
+        if let Some(last) = visible.last() {
+            let elapsed = last
+                .timestamp
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap_or_default();
+            ui.label(format!("Last shot at t={}s", elapsed.as_secs()));
+        }
+
+        Plot::new("replay_plot").height(240.0).show(ui, |plot_ui| {
+            let pts: Vec<[f64; 2]> = visible.iter().map(|s| [s.x_mm as f64, s.y_mm as f64]).collect();
+            plot_ui.points(Points::new(pts).radius(4.0));
+        });
+    }
+}
@@ -0,0 +1,170 @@
+//! Shot list panel: a sortable, filterable table with multi-select for
+//! bulk delete/exclude actions, replacing the flat per-shot listing.
+
+use std::collections::HashSet;
+
+use egui_extras::{Column, TableBuilder};
+
+use crate::session::Session;
+use crate::units::UnitSettings;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SortColumn {
+    Score,
+    Time,
+    Distance,
+}
+
+#[derive(Default)]
+pub struct ShotListState {
+    sort_by: Option<SortColumn>,
+    sort_ascending: bool,
+    filter_series: String,
+    filter_flagged_only: bool,
+    filter_manual_only: bool,
+    selected: HashSet<usize>,
+}
+
+impl ShotListState {
+    /// Returns the set of shot numbers to delete, if the user just clicked
+    /// "Delete selected" with a non-empty selection. The caller is
+    /// responsible for actually removing them (typically through the undo
+    /// stack) and should call `clear_selection` afterwards.
+    pub fn show(
+        &mut self,
+        ui: &mut egui::Ui,
+        session: &Session,
+        units: &UnitSettings,
+    ) -> Option<HashSet<usize>> {
+        let mut delete_requested = None;
+        ui.horizontal(|ui| {
+            ui.label("Filter series:");
+            ui.text_edit_singleline(&mut self.filter_series);
+            ui.checkbox(&mut self.filter_flagged_only, "Flagged only");
+            ui.checkbox(&mut self.filter_manual_only, "Manual only");
+            if ui.button("Delete selected").clicked() && !self.selected.is_empty() {
+                delete_requested = Some(self.selected.clone());
+            }
+        });
+
+        let mut rows: Vec<(usize, String, crate::session::Shot)> = session
+            .series
+            .iter()
+            .flat_map(|series| series.shots.iter().map(move |shot| (series.label.clone(), shot.clone())))
+            .map(|(label, shot)| (shot.number, label, shot))
+            .filter(|(_, label, shot)| {
+                (self.filter_series.is_empty() || label.contains(&self.filter_series))
+                    && (!self.filter_flagged_only || shot.flagged)
+                    && (!self.filter_manual_only || shot.manual)
+            })
+            .collect();
+
+        if let Some(column) = self.sort_by {
+            rows.sort_by(|a, b| {
+                let (_, _, sa) = a;
+                let (_, _, sb) = b;
+                let ord = match column {
+                    SortColumn::Score => sa.value.total_cmp(&sb.value),
+                    SortColumn::Time => sa.timestamp.cmp(&sb.timestamp),
+                    SortColumn::Distance => {
+                        let da = (sa.x_mm * sa.x_mm + sa.y_mm * sa.y_mm).sqrt();
+                        let db = (sb.x_mm * sb.x_mm + sb.y_mm * sb.y_mm).sqrt();
+                        da.total_cmp(&db)
+                    }
+                };
+                if self.sort_ascending { ord } else { ord.reverse() }
+            });
+        }
+
+        TableBuilder::new(ui)
+            .striped(true)
+            .column(Column::auto())
+            .column(Column::auto())
+            .column(Column::remainder())
+            .column(Column::auto())
+            .column(Column::auto())
+            .column(Column::remainder())
+            .header(20.0, |mut header| {
+                header.col(|ui| {
+                    ui.checkbox(&mut false, "");
+                });
+                header.col(|ui| {
+                    if ui.button("Score").clicked() {
+                        self.set_sort(SortColumn::Score);
+                    }
+                });
+                header.col(|ui| {
+                    if ui.button("Time").clicked() {
+                        self.set_sort(SortColumn::Time);
+                    }
+                });
+                header.col(|ui| {
+                    if ui.button("Distance").clicked() {
+                        self.set_sort(SortColumn::Distance);
+                    }
+                });
+                header.col(|ui| {
+                    ui.label("Split");
+                });
+                header.col(|ui| {
+                    ui.label("Note");
+                });
+            })
+            .body(|mut body| {
+                for (number, _label, shot) in &rows {
+                    body.row(18.0, |mut row| {
+                        row.col(|ui| {
+                            let mut selected = self.selected.contains(number);
+                            if ui.checkbox(&mut selected, "").changed() {
+                                if selected {
+                                    self.selected.insert(*number);
+                                } else {
+                                    self.selected.remove(number);
+                                }
+                            }
+                        });
+                        row.col(|ui| {
+                            ui.label(format!("{:.1}", shot.value));
+                        });
+                        row.col(|ui| {
+                            let elapsed = shot
+                                .timestamp
+                                .duration_since(std::time::UNIX_EPOCH)
+                                .unwrap_or_default();
+                            ui.label(format!("{}", elapsed.as_secs()));
+                        });
+                        row.col(|ui| {
+                            let distance = (shot.x_mm * shot.x_mm + shot.y_mm * shot.y_mm).sqrt();
+                            ui.label(crate::units::format_distance(distance, units));
+                        });
+                        row.col(|ui| {
+                            match shot.timer_split_secs {
+                                Some(split) => ui.label(format!("{split:.2}s")),
+                                None => ui.label(""),
+                            };
+                        });
+                        row.col(|ui| {
+                            ui.label(shot.note.as_deref().unwrap_or(""));
+                        });
+                    });
+                }
+            });
+
+        delete_requested
+    }
+
+    /// Drops the current row selection, typically called once the caller
+    /// has applied a delete returned from `show`.
+    pub fn clear_selection(&mut self) {
+        self.selected.clear();
+    }
+
+    fn set_sort(&mut self, column: SortColumn) {
+        if self.sort_by == Some(column) {
+            self.sort_ascending = !self.sort_ascending;
+        } else {
+            self.sort_by = Some(column);
+            self.sort_ascending = true;
+        }
+    }
+}
@@ -0,0 +1,42 @@
+//! Bottom status bar: camera resolution/FPS, processing time, contour vs
+//! hole counts, and the current calibration — a health-at-a-glance strip.
+
+use crate::calibration::ScoringConfig;
+use crate::camera::CameraStats;
+use crate::error::AppError;
+use crate::processor::ProcessorMetrics;
+
+pub fn show(
+    ui: &mut egui::Ui,
+    camera_stats: Option<CameraStats>,
+    metrics: ProcessorMetrics,
+    calibration: &ScoringConfig,
+    last_error: Option<&AppError>,
+) {
+    ui.horizontal(|ui| {
+        if let Some(stats) = camera_stats {
+            ui.label(format!(
+                "{}x{} @ {:.1} fps",
+                stats.resolution.0, stats.resolution.1, stats.fps
+            ));
+        } else {
+            ui.label("no camera");
+        }
+        ui.separator();
+        ui.label(format!("{:.1} ms/frame", metrics.processing_time.as_secs_f32() * 1000.0));
+        ui.separator();
+        ui.label(format!(
+            "{} contours → {} holes",
+            metrics.raw_contour_count, metrics.accepted_hole_count
+        ));
+        ui.separator();
+        ui.label(format!(
+            "calib: {:.3} px/mm, center {:?}",
+            calibration.pixels_per_mm, calibration.center_px
+        ));
+        if let Some(error) = last_error {
+            ui.separator();
+            ui.colored_label(egui::Color32::from_rgb(220, 80, 80), format!("⚠ {error}"));
+        }
+    });
+}
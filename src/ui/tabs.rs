@@ -0,0 +1,67 @@
+//! Static-image tabs: scoring a stack of cards without losing per-card
+//! detections and scoring state when flipping between them.
+
+use image::RgbImage;
+
+use crate::calibration::ScoringConfig;
+use crate::processor::Processor;
+use crate::session::{Session, Shooter};
+
+pub struct StaticTab {
+    pub title: String,
+    pub image: RgbImage,
+    pub session: Session,
+    pub calibration: ScoringConfig,
+    pub processor: Processor,
+}
+
+impl StaticTab {
+    pub fn new(title: String, image: RgbImage) -> Self {
+        Self {
+            title,
+            image,
+            session: Session::new(Shooter::default()),
+            calibration: ScoringConfig::default(),
+            processor: Processor::default(),
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct TabbedImages {
+    pub tabs: Vec<StaticTab>,
+    pub active: usize,
+}
+
+impl TabbedImages {
+    pub fn open(&mut self, title: String, image: RgbImage) {
+        self.tabs.push(StaticTab::new(title, image));
+        self.active = self.tabs.len() - 1;
+    }
+
+    pub fn close(&mut self, index: usize) {
+        if index < self.tabs.len() {
+            self.tabs.remove(index);
+            self.active = self.active.min(self.tabs.len().saturating_sub(1));
+        }
+    }
+
+    pub fn active_tab(&mut self) -> Option<&mut StaticTab> {
+        self.tabs.get_mut(self.active)
+    }
+
+    pub fn show_strip(&mut self, ui: &mut egui::Ui) {
+        ui.horizontal(|ui| {
+            let mut to_close = None;
+            for (i, tab) in self.tabs.iter().enumerate() {
+                ui.selectable_value(&mut self.active, i, &tab.title);
+                if ui.small_button("x").clicked() {
+                    to_close = Some(i);
+                }
+            }
+            if let Some(i) = to_close {
+                self.close(i);
+            }
+        });
+    }
+}
@@ -0,0 +1,69 @@
+//! Selectable color themes, including a high-contrast mode tuned for
+//! range TVs viewed from a distance under bright ambient light.
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Theme {
+    Light,
+    Dark,
+    /// Pure black background, white text, and a saturated accent — meant
+    /// to stay legible on a range TV rather than to look refined.
+    HighContrast,
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Theme::Dark
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ThemeSettings {
+    pub theme: Theme,
+    pub accent_color: [u8; 3],
+}
+
+impl Default for ThemeSettings {
+    fn default() -> Self {
+        Self {
+            theme: Theme::default(),
+            accent_color: [90, 170, 255],
+        }
+    }
+}
+
+impl ThemeSettings {
+    /// Applies the selected theme and accent color to the egui context's
+    /// visuals. Called once per frame; cheap enough that we don't bother
+    /// caching whether it changed.
+    pub fn apply(&self, ctx: &egui::Context) {
+        let mut visuals = match self.theme {
+            Theme::Light => egui::Visuals::light(),
+            Theme::Dark => egui::Visuals::dark(),
+            Theme::HighContrast => high_contrast_visuals(),
+        };
+        let accent = egui::Color32::from_rgb(
+            self.accent_color[0],
+            self.accent_color[1],
+            self.accent_color[2],
+        );
+        visuals.selection.bg_fill = accent;
+        visuals.hyperlink_color = accent;
+        ctx.set_visuals(visuals);
+    }
+}
+
+fn high_contrast_visuals() -> egui::Visuals {
+    let mut visuals = egui::Visuals::dark();
+    visuals.override_text_color = Some(egui::Color32::WHITE);
+    visuals.panel_fill = egui::Color32::BLACK;
+    visuals.window_fill = egui::Color32::BLACK;
+    visuals.extreme_bg_color = egui::Color32::BLACK;
+    visuals.faint_bg_color = egui::Color32::from_gray(20);
+    visuals.widgets.noninteractive.bg_fill = egui::Color32::BLACK;
+    visuals.widgets.inactive.bg_fill = egui::Color32::from_gray(30);
+    visuals.widgets.hovered.bg_fill = egui::Color32::from_gray(60);
+    visuals.widgets.active.bg_fill = egui::Color32::from_gray(90);
+    visuals
+}
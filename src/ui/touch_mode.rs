@@ -0,0 +1,67 @@
+//! Touch-friendly mode for running the app on a tablet at the firing
+//! point: larger hit targets, tap-and-hold loupe, pinch-to-zoom.
+
+/// Multiplier applied to button padding and font size when touch mode is
+/// enabled, and the minimum duration a touch must be held to trigger the
+/// center/loupe action instead of a tap.
+#[derive(Debug, Clone, Copy)]
+pub struct TouchModeConfig {
+    pub enabled: bool,
+    pub hit_target_scale: f32,
+    pub hold_duration: std::time::Duration,
+}
+
+impl Default for TouchModeConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            hit_target_scale: 1.6,
+            hold_duration: std::time::Duration::from_millis(400),
+        }
+    }
+}
+
+impl TouchModeConfig {
+    /// Applies larger spacing/text sizes to the given egui style when
+    /// touch mode is on; a no-op otherwise.
+    pub fn apply(&self, style: &mut egui::Style) {
+        if !self.enabled {
+            return;
+        }
+        style.spacing.button_padding *= self.hit_target_scale;
+        style.spacing.item_spacing *= self.hit_target_scale;
+        style.spacing.interact_size *= self.hit_target_scale;
+        for (_, font_id) in style.text_styles.iter_mut() {
+            font_id.size *= self.hit_target_scale;
+        }
+    }
+}
+
+/// Tracks a press-and-hold gesture, used to trigger the loupe/center pick
+/// on long-press instead of on every tap.
+#[derive(Default)]
+pub struct HoldTracker {
+    pressed_since: Option<std::time::Instant>,
+}
+
+impl HoldTracker {
+    /// Call every frame with whether the pointer is currently down. Returns
+    /// true exactly once, the frame the hold duration is first exceeded.
+    pub fn update(&mut self, is_down: bool, hold_duration: std::time::Duration) -> bool {
+        match (is_down, self.pressed_since) {
+            (true, None) => {
+                self.pressed_since = Some(std::time::Instant::now());
+                false
+            }
+            (true, Some(started)) => {
+                let just_triggered = started.elapsed() >= hold_duration
+                    && started.elapsed() - hold_duration < std::time::Duration::from_millis(50);
+                just_triggered
+            }
+            (false, _) => {
+                self.pressed_since = None;
+                false
+            }
+        }
+    }
+}
@@ -0,0 +1,83 @@
+//! Score trend panel: shot value over shot number with a rolling average,
+//! and group size over time — useful for spotting fatigue mid-session.
+
+use egui_plot::{Line, Plot, PlotPoints};
+
+use crate::session::{Session, Shot};
+use crate::units::UnitSettings;
+
+const ROLLING_WINDOW: usize = 5;
+
+pub fn show(ui: &mut egui::Ui, session: &Session, units: &UnitSettings) {
+    let shots: Vec<&Shot> = session.all_shots().collect();
+    if shots.is_empty() {
+        ui.label("No shots yet.");
+        return;
+    }
+
+    ui.label("Shot value");
+    Plot::new("score_trend_plot")
+        .height(160.0)
+        .show(ui, |plot_ui| {
+            let values: PlotPoints = shots
+                .iter()
+                .enumerate()
+                .map(|(i, s)| [i as f64 + 1.0, s.value as f64])
+                .collect();
+            plot_ui.line(Line::new(values).name("Shot value"));
+
+            let rolling: PlotPoints = rolling_average(&shots, ROLLING_WINDOW)
+                .into_iter()
+                .enumerate()
+                .map(|(i, v)| [i as f64 + 1.0, v as f64])
+                .collect();
+            plot_ui.line(Line::new(rolling).name("Rolling average"));
+        });
+
+    let unit_suffix = match units.system {
+        crate::units::UnitSystem::Metric => "mm",
+        crate::units::UnitSystem::Imperial => "in",
+    };
+    ui.label(format!("Group size over time ({unit_suffix})"));
+    if let Some(last_mm) = cumulative_group_size(&shots).last() {
+        ui.label(crate::units::format_group_size(*last_mm, units));
+    }
+    Plot::new("group_size_plot").height(160.0).show(ui, |plot_ui| {
+        let group_sizes: PlotPoints = cumulative_group_size(&shots)
+            .into_iter()
+            .enumerate()
+            .map(|(i, v)| [i as f64 + 1.0, crate::units::convert_distance(v, units) as f64])
+            .collect();
+        plot_ui.line(Line::new(group_sizes).name(format!("Group size ({unit_suffix})")));
+    });
+}
+
+fn rolling_average(shots: &[&Shot], window: usize) -> Vec<f32> {
+    shots
+        .iter()
+        .enumerate()
+        .map(|(i, _)| {
+            let start = i.saturating_sub(window - 1);
+            let slice = &shots[start..=i];
+            slice.iter().map(|s| s.value).sum::<f32>() / slice.len() as f32
+        })
+        .collect()
+}
+
+/// Extreme spread (max pairwise distance) among all shots fired so far,
+/// recomputed at each shot count. O(n^2) but session sizes are small.
+fn cumulative_group_size(shots: &[&Shot]) -> Vec<f32> {
+    let mut out = Vec::with_capacity(shots.len());
+    for i in 0..shots.len() {
+        let mut max_dist = 0.0f32;
+        for a in 0..=i {
+            for b in (a + 1)..=i {
+                let dx = shots[a].x_mm - shots[b].x_mm;
+                let dy = shots[a].y_mm - shots[b].y_mm;
+                max_dist = max_dist.max((dx * dx + dy * dy).sqrt());
+            }
+        }
+        out.push(max_dist);
+    }
+    out
+}
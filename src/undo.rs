@@ -0,0 +1,150 @@
+//! Command-based undo/redo for destructive scoring actions (reset, delete
+//! shot, center moves, manual edits).
+
+use crate::session::Session;
+
+/// A reversible mutation of the session. `apply` performs the action and
+/// returns its inverse, so `UndoStack` never needs bespoke undo logic per
+/// command.
+pub trait Command {
+    fn apply(&self, session: &mut Session) -> Box<dyn Command>;
+    fn label(&self) -> &str;
+}
+
+pub struct ReplaceSession {
+    pub label: String,
+    pub snapshot: Session,
+}
+
+impl Command for ReplaceSession {
+    fn apply(&self, session: &mut Session) -> Box<dyn Command> {
+        let previous = ReplaceSession {
+            label: self.label.clone(),
+            snapshot: session.clone(),
+        };
+        *session = self.snapshot.clone();
+        Box::new(previous)
+    }
+
+    fn label(&self) -> &str {
+        &self.label
+    }
+}
+
+#[derive(Default)]
+pub struct UndoStack {
+    undo: Vec<Box<dyn Command>>,
+    redo: Vec<Box<dyn Command>>,
+}
+
+impl UndoStack {
+    /// Records `command`, applies it to `session`, and clears the redo
+    /// stack (a fresh action invalidates any previously undone branch).
+    pub fn do_command(&mut self, session: &mut Session, command: Box<dyn Command>) {
+        let inverse = command.apply(session);
+        self.undo.push(inverse);
+        self.redo.clear();
+    }
+
+    pub fn undo(&mut self, session: &mut Session) -> bool {
+        let Some(command) = self.undo.pop() else {
+            return false;
+        };
+        let inverse = command.apply(session);
+        self.redo.push(inverse);
+        true
+    }
+
+    pub fn redo(&mut self, session: &mut Session) -> bool {
+        let Some(command) = self.redo.pop() else {
+            return false;
+        };
+        let inverse = command.apply(session);
+        self.undo.push(inverse);
+        true
+    }
+
+    pub fn can_undo(&self) -> bool {
+        !self.undo.is_empty()
+    }
+
+    pub fn can_redo(&self) -> bool {
+        !self.redo.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::session::Shooter;
+
+    fn named(session: &mut Session, name: &str) {
+        session.shooter.name = name.to_string();
+    }
+
+    #[test]
+    fn undo_restores_the_previous_snapshot() {
+        let mut session = Session::new(Shooter::default());
+        named(&mut session, "before");
+        let mut stack = UndoStack::default();
+
+        let mut after = session.clone();
+        named(&mut after, "after");
+        stack.do_command(&mut session, Box::new(ReplaceSession { label: "rename".to_string(), snapshot: after }));
+        assert_eq!(session.shooter.name, "after");
+
+        assert!(stack.undo(&mut session));
+        assert_eq!(session.shooter.name, "before");
+    }
+
+    #[test]
+    fn redo_reapplies_an_undone_command() {
+        let mut session = Session::new(Shooter::default());
+        let mut stack = UndoStack::default();
+        let mut after = session.clone();
+        named(&mut after, "after");
+        stack.do_command(&mut session, Box::new(ReplaceSession { label: "rename".to_string(), snapshot: after }));
+
+        stack.undo(&mut session);
+        assert!(stack.redo(&mut session));
+        assert_eq!(session.shooter.name, "after");
+    }
+
+    #[test]
+    fn undo_and_redo_on_empty_stacks_return_false() {
+        let mut session = Session::new(Shooter::default());
+        let mut stack = UndoStack::default();
+        assert!(!stack.undo(&mut session));
+        assert!(!stack.redo(&mut session));
+    }
+
+    #[test]
+    fn a_new_command_clears_the_redo_stack() {
+        let mut session = Session::new(Shooter::default());
+        let mut stack = UndoStack::default();
+        let mut after = session.clone();
+        named(&mut after, "after");
+        stack.do_command(&mut session, Box::new(ReplaceSession { label: "rename".to_string(), snapshot: after }));
+        stack.undo(&mut session);
+        assert!(stack.can_redo());
+
+        let mut other = session.clone();
+        named(&mut other, "other");
+        stack.do_command(&mut session, Box::new(ReplaceSession { label: "rename again".to_string(), snapshot: other }));
+        assert!(!stack.can_redo());
+    }
+
+    #[test]
+    fn can_undo_and_can_redo_reflect_stack_state() {
+        let mut session = Session::new(Shooter::default());
+        let mut stack = UndoStack::default();
+        assert!(!stack.can_undo());
+        assert!(!stack.can_redo());
+
+        let mut after = session.clone();
+        named(&mut after, "after");
+        stack.do_command(&mut session, Box::new(ReplaceSession { label: "rename".to_string(), snapshot: after }));
+        assert!(stack.can_undo());
+        assert!(!stack.can_redo());
+    }
+}
@@ -0,0 +1,131 @@
+//! Unit system abstraction: every place that displays a distance or
+//! group size goes through [`format_distance`]/[`format_group_size`]
+//! instead of hardcoding an "mm" suffix, so switching to imperial (or
+//! reading group spread in MOA) is one setting instead of a UI-wide
+//! find-and-replace.
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum UnitSystem {
+    #[default]
+    Metric,
+    Imperial,
+}
+
+/// Persisted alongside the rest of [`crate::settings::Settings`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UnitSettings {
+    pub system: UnitSystem,
+    /// Distance to the target, in meters. Zero disables the MOA readout,
+    /// since MOA is meaningless without knowing how far away the target is.
+    pub target_distance_m: f32,
+}
+
+impl Default for UnitSettings {
+    fn default() -> Self {
+        Self { system: UnitSystem::Metric, target_distance_m: 0.0 }
+    }
+}
+
+const MM_PER_INCH: f32 = 25.4;
+const METERS_PER_YARD: f32 = 0.9144;
+/// Inches subtended by 1 MOA at 100 yards.
+const INCHES_PER_MOA_AT_100YD: f32 = 1.047;
+
+/// Converts a distance in mm to `settings.system`'s unit (mm or inches),
+/// without formatting — for plots and other numeric consumers.
+pub fn convert_distance(mm: f32, settings: &UnitSettings) -> f32 {
+    match settings.system {
+        UnitSystem::Metric => mm,
+        UnitSystem::Imperial => mm / MM_PER_INCH,
+    }
+}
+
+/// Formats a distance in mm as `"12.3 mm"` or `"0.48 in"` depending on
+/// `settings.system`.
+pub fn format_distance(mm: f32, settings: &UnitSettings) -> String {
+    match settings.system {
+        UnitSystem::Metric => format!("{mm:.1} mm"),
+        UnitSystem::Imperial => format!("{:.2} in", mm / MM_PER_INCH),
+    }
+}
+
+/// Formats a group size the same way as [`format_distance`], with an
+/// appended MOA reading when a target distance is configured.
+pub fn format_group_size(mm: f32, settings: &UnitSettings) -> String {
+    let base = format_distance(mm, settings);
+    match moa(mm, settings.target_distance_m) {
+        Some(moa) => format!("{base} ({moa:.2} MOA)"),
+        None => base,
+    }
+}
+
+/// Minutes of angle subtended by a `mm` group size at `distance_m`
+/// meters; `None` if no target distance is configured.
+fn moa(mm: f32, distance_m: f32) -> Option<f32> {
+    if distance_m <= 0.0 {
+        return None;
+    }
+    let inches = mm / MM_PER_INCH;
+    let distance_yards = distance_m / METERS_PER_YARD;
+    let inches_per_moa = INCHES_PER_MOA_AT_100YD * (distance_yards / 100.0);
+    Some(inches / inches_per_moa)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn settings(system: UnitSystem, target_distance_m: f32) -> UnitSettings {
+        UnitSettings { system, target_distance_m }
+    }
+
+    #[test]
+    fn convert_distance_metric_is_identity() {
+        assert_eq!(convert_distance(25.4, &settings(UnitSystem::Metric, 0.0)), 25.4);
+    }
+
+    #[test]
+    fn convert_distance_imperial_divides_by_mm_per_inch() {
+        assert!((convert_distance(25.4, &settings(UnitSystem::Imperial, 0.0)) - 1.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn format_distance_metric() {
+        assert_eq!(format_distance(12.34, &settings(UnitSystem::Metric, 0.0)), "12.3 mm");
+    }
+
+    #[test]
+    fn format_distance_imperial() {
+        assert_eq!(format_distance(25.4, &settings(UnitSystem::Imperial, 0.0)), "1.00 in");
+    }
+
+    #[test]
+    fn format_group_size_without_target_distance_omits_moa() {
+        let out = format_group_size(10.0, &settings(UnitSystem::Metric, 0.0));
+        assert!(!out.contains("MOA"), "expected no MOA reading, got {out}");
+    }
+
+    #[test]
+    fn format_group_size_with_target_distance_appends_moa() {
+        let out = format_group_size(10.0, &settings(UnitSystem::Metric, 100.0));
+        assert!(out.contains("MOA"), "expected a MOA reading, got {out}");
+    }
+
+    #[test]
+    fn moa_is_none_for_zero_or_negative_distance() {
+        assert_eq!(moa(10.0, 0.0), None);
+        assert_eq!(moa(10.0, -5.0), None);
+    }
+
+    #[test]
+    fn moa_one_inch_group_at_100_yards_is_about_one_moa() {
+        // 1 MOA subtends ~1.047 in at 100 yd by definition, so a 1 in
+        // group there should read back as slightly under 1 MOA.
+        let mm = MM_PER_INCH;
+        let distance_m = 100.0 * METERS_PER_YARD;
+        let moa = moa(mm, distance_m).expect("target distance is set");
+        assert!((moa - (1.0 / INCHES_PER_MOA_AT_100YD)).abs() < 1e-3, "got {moa}");
+    }
+}
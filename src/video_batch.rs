@@ -0,0 +1,270 @@
+//! Offline batch scoring of a recorded session video: decodes frames with
+//! `ffmpeg-next`, runs the same [`crate::processor::Processor`] pipeline
+//! used for live capture on each one, and only registers a shot the
+//! first time a hole appears near a given position — the same temporal
+//! "new hole" logic the live camera path relies on — so a hole still
+//! visible in the next hundred frames doesn't get counted a hundred
+//! times. Produces the resulting `Session` plus an annotated output
+//! video with the same rings/hole overlay the live view draws.
+
+use std::path::Path;
+use std::time::SystemTime;
+
+use image::RgbImage;
+
+use crate::export::image::render_annotated;
+use crate::headless::BatchConfig;
+use crate::overlay::OverlaySettings;
+use crate::processor::{Detection, Processor, ProcessorSettings};
+use crate::session::{Series, Session, Shooter, Shot};
+use crate::target::TargetType;
+
+/// How close (px) two detections must be to count as the same hole
+/// across frames.
+const SAME_HOLE_RADIUS_PX: f32 = 6.0;
+
+pub struct VideoScoreResult {
+    pub session: Session,
+    pub frames_processed: usize,
+}
+
+/// CLI entry point: loads the same JSON calibration format as
+/// `--headless`, scores `input`, writes the annotated video to
+/// `output_video`, and a `<output_video>.json` session summary next to it.
+pub fn run(input: &Path, config_path: &Path, output_video: &Path) -> Result<(), String> {
+    let config_json = std::fs::read_to_string(config_path).map_err(|e| e.to_string())?;
+    let config: BatchConfig = serde_json::from_str(&config_json).map_err(|e| e.to_string())?;
+    let target = crate::target::by_name(&config.target_preset).unwrap_or_else(crate::target::issf_10m);
+
+    let mut processor = Processor {
+        settings: ProcessorSettings {
+            threshold: config.threshold,
+            min_contour_area: config.min_contour_area,
+            max_contour_area: config.max_contour_area,
+            ..ProcessorSettings::default()
+        },
+        ..Processor::default()
+    };
+
+    let result =
+        score_video(input, output_video, &mut processor, config.center_px, config.pixels_per_mm, &target)?;
+
+    let summary_path = output_video.with_extension("json");
+    let summary = serde_json::to_string_pretty(&result.session).map_err(|e| e.to_string())?;
+    std::fs::write(&summary_path, summary).map_err(|e| e.to_string())?;
+
+    println!(
+        "processed {} frame(s), {} shot(s) scored, wrote {} and {}",
+        result.frames_processed,
+        result.session.all_shots().count(),
+        output_video.display(),
+        summary_path.display()
+    );
+    Ok(())
+}
+
+/// Scores every frame of `input` and writes an annotated copy to
+/// `output_video`. `center_px`/`pixels_per_mm` are the same calibration
+/// values the live GUI would use.
+pub fn score_video(
+    input: &Path,
+    output_video: &Path,
+    processor: &mut Processor,
+    center_px: (f32, f32),
+    pixels_per_mm: f32,
+    target: &TargetType,
+) -> Result<VideoScoreResult, String> {
+    ffmpeg_next::init().map_err(|e| e.to_string())?;
+
+    let mut input_ctx = ffmpeg_next::format::input(input).map_err(|e| e.to_string())?;
+    let input_stream =
+        input_ctx.streams().best(ffmpeg_next::media::Type::Video).ok_or("no video stream found")?;
+    let stream_index = input_stream.index();
+    let context = ffmpeg_next::codec::context::Context::from_parameters(input_stream.parameters())
+        .map_err(|e| e.to_string())?;
+    let mut decoder = context.decoder().video().map_err(|e| e.to_string())?;
+
+    let mut to_rgb = ffmpeg_next::software::scaling::context::Context::get(
+        decoder.format(),
+        decoder.width(),
+        decoder.height(),
+        ffmpeg_next::format::Pixel::RGB24,
+        decoder.width(),
+        decoder.height(),
+        ffmpeg_next::software::scaling::flag::Flags::BILINEAR,
+    )
+    .map_err(|e| e.to_string())?;
+
+    let mut writer = AnnotatedVideoWriter::create(output_video, decoder.width(), decoder.height())?;
+
+    let mut session = Session::new(Shooter::default());
+    session.series.push(Series { label: "Video batch".to_string(), shots: Vec::new() });
+    let mut known_holes: Vec<Detection> = Vec::new();
+    let mut frames_processed = 0usize;
+
+    for (stream, packet) in input_ctx.packets() {
+        if stream.index() != stream_index {
+            continue;
+        }
+        decoder.send_packet(&packet).map_err(|e| e.to_string())?;
+        let mut decoded = ffmpeg_next::frame::Video::empty();
+        while decoder.receive_frame(&mut decoded).is_ok() {
+            let mut rgb = ffmpeg_next::frame::Video::empty();
+            to_rgb.run(&decoded, &mut rgb).map_err(|e| e.to_string())?;
+            let frame = rgb_frame_to_image(&rgb, decoder.width(), decoder.height());
+            frames_processed += 1;
+
+            register_new_shots(&mut session, &mut known_holes, processor, &frame, center_px, pixels_per_mm, target);
+
+            let annotated =
+                render_annotated(&frame, center_px, pixels_per_mm, 0.0, &session, target, &OverlaySettings::default());
+            writer.write_frame(&annotated)?;
+        }
+    }
+
+    writer.finish()?;
+    Ok(VideoScoreResult { session, frames_processed })
+}
+
+/// Runs the detector on `frame` and appends a `Shot` for every detection
+/// that isn't within `SAME_HOLE_RADIUS_PX` of an already-known hole.
+fn register_new_shots(
+    session: &mut Session,
+    known_holes: &mut Vec<Detection>,
+    processor: &mut Processor,
+    frame: &RgbImage,
+    center_px: (f32, f32),
+    pixels_per_mm: f32,
+    target: &TargetType,
+) {
+    for detection in processor.process(frame) {
+        let already_known = known_holes
+            .iter()
+            .any(|known| distance(known.center_px, detection.center_px) < SAME_HOLE_RADIUS_PX);
+        if already_known {
+            continue;
+        }
+        known_holes.push(detection);
+
+        let x_mm = (detection.center_px.0 - center_px.0) / pixels_per_mm;
+        let y_mm = (center_px.1 - detection.center_px.1) / pixels_per_mm;
+        let distance_mm = (x_mm * x_mm + y_mm * y_mm).sqrt();
+        let (value, is_x) = target.score(distance_mm);
+
+        let series = session.series.last_mut().expect("score_video always seeds one series");
+        series.shots.push(Shot {
+            number: series.shots.len() + 1,
+            x_mm,
+            y_mm,
+            value,
+            is_x,
+            timestamp: SystemTime::now(),
+            note: None,
+            flagged: false,
+            manual: false,
+            timer_split_secs: None,
+            acoustic_confirmed: false,
+        });
+    }
+}
+
+fn distance(a: (f32, f32), b: (f32, f32)) -> f32 {
+    ((a.0 - b.0).powi(2) + (a.1 - b.1).powi(2)).sqrt()
+}
+
+fn rgb_frame_to_image(frame: &ffmpeg_next::frame::Video, width: u32, height: u32) -> RgbImage {
+    let mut out = RgbImage::new(width, height);
+    let stride = frame.stride(0);
+    let data = frame.data(0);
+    for y in 0..height as usize {
+        for x in 0..width as usize {
+            let offset = y * stride + x * 3;
+            out.put_pixel(x as u32, y as u32, image::Rgb([data[offset], data[offset + 1], data[offset + 2]]));
+        }
+    }
+    out
+}
+
+/// Encodes annotated frames to an H.264/MP4 output file, one frame per
+/// input frame (no attempt to preserve the source's exact frame rate
+/// metadata — batch scoring cares about the shot list, not playback).
+struct AnnotatedVideoWriter {
+    output_ctx: ffmpeg_next::format::context::Output,
+    encoder: ffmpeg_next::encoder::Video,
+    to_yuv: ffmpeg_next::software::scaling::context::Context,
+    stream_index: usize,
+    frame_index: i64,
+}
+
+impl AnnotatedVideoWriter {
+    fn create(path: &Path, width: u32, height: u32) -> Result<Self, String> {
+        let mut output_ctx = ffmpeg_next::format::output(path).map_err(|e| e.to_string())?;
+        let codec = ffmpeg_next::encoder::find(ffmpeg_next::codec::Id::H264).ok_or("no H.264 encoder available")?;
+        let mut stream = output_ctx.add_stream(codec).map_err(|e| e.to_string())?;
+        let mut encoder = ffmpeg_next::codec::context::Context::new_with_codec(codec)
+            .encoder()
+            .video()
+            .map_err(|e| e.to_string())?;
+        encoder.set_width(width);
+        encoder.set_height(height);
+        encoder.set_format(ffmpeg_next::format::Pixel::YUV420P);
+        encoder.set_time_base(ffmpeg_next::Rational(1, 30));
+        let encoder = encoder.open_as(codec).map_err(|e| e.to_string())?;
+        stream.set_parameters(&encoder);
+        let stream_index = stream.index();
+
+        output_ctx.write_header().map_err(|e| e.to_string())?;
+
+        let to_yuv = ffmpeg_next::software::scaling::context::Context::get(
+            ffmpeg_next::format::Pixel::RGB24,
+            width,
+            height,
+            ffmpeg_next::format::Pixel::YUV420P,
+            width,
+            height,
+            ffmpeg_next::software::scaling::flag::Flags::BILINEAR,
+        )
+        .map_err(|e| e.to_string())?;
+
+        Ok(Self { output_ctx, encoder, to_yuv, stream_index, frame_index: 0 })
+    }
+
+    fn write_frame(&mut self, frame: &RgbImage) -> Result<(), String> {
+        let (width, height) = frame.dimensions();
+        let mut rgb = ffmpeg_next::frame::Video::new(ffmpeg_next::format::Pixel::RGB24, width, height);
+        let stride = rgb.stride(0);
+        {
+            let data = rgb.data_mut(0);
+            for y in 0..height as usize {
+                for x in 0..width as usize {
+                    let pixel = frame.get_pixel(x as u32, y as u32);
+                    let offset = y * stride + x * 3;
+                    data[offset..offset + 3].copy_from_slice(&pixel.0);
+                }
+            }
+        }
+
+        let mut yuv = ffmpeg_next::frame::Video::empty();
+        self.to_yuv.run(&rgb, &mut yuv).map_err(|e| e.to_string())?;
+        yuv.set_pts(Some(self.frame_index));
+        self.frame_index += 1;
+
+        self.encoder.send_frame(&yuv).map_err(|e| e.to_string())?;
+        self.drain_packets()
+    }
+
+    fn drain_packets(&mut self) -> Result<(), String> {
+        let mut packet = ffmpeg_next::Packet::empty();
+        while self.encoder.receive_packet(&mut packet).is_ok() {
+            packet.set_stream(self.stream_index);
+            packet.write_interleaved(&mut self.output_ctx).map_err(|e| e.to_string())?;
+        }
+        Ok(())
+    }
+
+    fn finish(mut self) -> Result<(), String> {
+        self.encoder.send_eof().map_err(|e| e.to_string())?;
+        self.drain_packets()?;
+        self.output_ctx.write_trailer().map_err(|e| e.to_string())
+    }
+}
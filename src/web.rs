@@ -0,0 +1,37 @@
+//! WebAssembly entry point: runs the same [`crate::app::MyApp`] inside a
+//! browser canvas via `eframe::WebRunner`, so static-image and (once
+//! wired up) webcam scoring can run from a web page instead of an
+//! installed binary.
+//!
+//! This lands the wasm32 target and the browser camera capture stub
+//! (see the `wasm32` branch of [`crate::camera::Camera::read_frame`])
+//! but not a full port: every `rfd::FileDialog` call in `app.rs` still
+//! uses rfd's synchronous native API, which panics on wasm32, and the
+//! MQTT/UDP/WebSocket/API-server network outputs assume a native socket
+//! stack. Switching those call sites to rfd's async wasm API and to
+//! browser-native transports (`fetch`, `WebSocket`) is real, separate
+//! work left for a follow-up; this module only unblocks compiling and
+//! running the core UI and detection pipeline in a browser tab.
+
+#![cfg(target_arch = "wasm32")]
+
+use wasm_bindgen::prelude::*;
+
+/// Mounts the app onto the `<canvas id="precision_scorer_canvas">`
+/// element on the host page.
+#[wasm_bindgen(start)]
+pub fn start_web() -> Result<(), JsValue> {
+    crate::telemetry::init();
+    let web_options = eframe::WebOptions::default();
+    wasm_bindgen_futures::spawn_local(async {
+        eframe::WebRunner::new()
+            .start(
+                "precision_scorer_canvas",
+                web_options,
+                Box::new(|_cc| Ok(Box::<crate::app::MyApp>::default())),
+            )
+            .await
+            .expect("failed to start eframe on the web canvas");
+    });
+    Ok(())
+}
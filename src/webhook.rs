@@ -0,0 +1,134 @@
+//! Posts a milestone notification (series complete, personal best) to a
+//! configurable webhook, with the annotated target image attached so the
+//! recipient sees the group without opening the app.
+
+use std::io;
+
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum WebhookKind {
+    #[default]
+    Disabled,
+    Discord,
+    Telegram,
+    Generic,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WebhookConfig {
+    pub kind: WebhookKind,
+    /// Discord/generic webhook URL, unused for Telegram.
+    pub url: String,
+    pub telegram_bot_token: String,
+    pub telegram_chat_id: String,
+}
+
+impl Default for WebhookConfig {
+    fn default() -> Self {
+        Self {
+            kind: WebhookKind::default(),
+            url: String::new(),
+            telegram_bot_token: String::new(),
+            telegram_chat_id: String::new(),
+        }
+    }
+}
+
+/// Posts `message` plus `image_png` (the annotated target, PNG-encoded)
+/// to whichever backend is configured; a no-op when disabled so callers
+/// can call this unconditionally.
+pub fn post_milestone(config: &WebhookConfig, message: &str, image_png: &[u8]) -> io::Result<()> {
+    match config.kind {
+        WebhookKind::Disabled => Ok(()),
+        WebhookKind::Discord => post_discord(config, message, image_png),
+        WebhookKind::Telegram => post_telegram(config, message, image_png),
+        WebhookKind::Generic => post_generic(config, message, image_png),
+    }
+}
+
+fn post_discord(config: &WebhookConfig, message: &str, image_png: &[u8]) -> io::Result<()> {
+    let boundary = "----precision-scorer-webhook";
+    let payload = json!({ "content": message }).to_string();
+    let body = multipart_body(
+        boundary,
+        &[("payload_json", &payload)],
+        "files[0]",
+        "target.png",
+        "image/png",
+        image_png,
+    );
+    ureq::post(&config.url)
+        .set("Content-Type", &format!("multipart/form-data; boundary={boundary}"))
+        .send_bytes(&body)
+        .map_err(|e| io::Error::new(io::ErrorKind::Other, e.to_string()))?;
+    Ok(())
+}
+
+fn post_telegram(config: &WebhookConfig, message: &str, image_png: &[u8]) -> io::Result<()> {
+    let boundary = "----precision-scorer-webhook";
+    let url = format!("https://api.telegram.org/bot{}/sendPhoto", config.telegram_bot_token);
+    let body = multipart_body(
+        boundary,
+        &[("chat_id", &config.telegram_chat_id), ("caption", message)],
+        "photo",
+        "target.png",
+        "image/png",
+        image_png,
+    );
+    ureq::post(&url)
+        .set("Content-Type", &format!("multipart/form-data; boundary={boundary}"))
+        .send_bytes(&body)
+        .map_err(|e| io::Error::new(io::ErrorKind::Other, e.to_string()))?;
+    Ok(())
+}
+
+/// Plain JSON POST for generic receivers, with the image base64-encoded
+/// inline rather than as a file part.
+fn post_generic(config: &WebhookConfig, message: &str, image_png: &[u8]) -> io::Result<()> {
+    use base64::engine::general_purpose::STANDARD as BASE64;
+    use base64::Engine as _;
+
+    let body = json!({
+        "message": message,
+        "image_png_base64": BASE64.encode(image_png),
+    });
+    ureq::post(&config.url)
+        .send_json(body)
+        .map_err(|e| io::Error::new(io::ErrorKind::Other, e.to_string()))?;
+    Ok(())
+}
+
+/// Builds a minimal `multipart/form-data` body: one text field per entry
+/// in `fields`, followed by a single file part.
+fn multipart_body(
+    boundary: &str,
+    fields: &[(&str, &str)],
+    file_field: &str,
+    filename: &str,
+    content_type: &str,
+    file_bytes: &[u8],
+) -> Vec<u8> {
+    let mut body = Vec::new();
+    for (name, value) in fields {
+        body.extend_from_slice(format!("--{boundary}\r\n").as_bytes());
+        body.extend_from_slice(
+            format!("Content-Disposition: form-data; name=\"{name}\"\r\n\r\n").as_bytes(),
+        );
+        body.extend_from_slice(value.as_bytes());
+        body.extend_from_slice(b"\r\n");
+    }
+    body.extend_from_slice(format!("--{boundary}\r\n").as_bytes());
+    body.extend_from_slice(
+        format!(
+            "Content-Disposition: form-data; name=\"{file_field}\"; filename=\"{filename}\"\r\n\
+             Content-Type: {content_type}\r\n\r\n"
+        )
+        .as_bytes(),
+    );
+    body.extend_from_slice(file_bytes);
+    body.extend_from_slice(b"\r\n");
+    body.extend_from_slice(format!("--{boundary}--\r\n").as_bytes());
+    body
+}
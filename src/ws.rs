@@ -0,0 +1,81 @@
+//! Broadcasts a JSON event over WebSocket every time a shot is scored, so
+//! external scoreboards and stream overlays can update instantly instead
+//! of polling the REST API.
+
+use std::net::TcpListener;
+use std::sync::mpsc::{self, Sender};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::SystemTime;
+
+use serde::Serialize;
+use tungstenite::Message;
+
+use crate::session::Shot;
+
+#[derive(Serialize)]
+struct ShotEvent {
+    number: usize,
+    value: f32,
+    x_mm: f32,
+    y_mm: f32,
+    is_x: bool,
+    timestamp: SystemTime,
+}
+
+pub struct ShotBroadcaster {
+    clients: Arc<Mutex<Vec<Sender<String>>>>,
+    addr: String,
+}
+
+impl ShotBroadcaster {
+    /// Binds `addr` (e.g. `"127.0.0.1:8788"`) and accepts WebSocket
+    /// connections in a background thread; each connection gets its own
+    /// writer thread fed from a channel so one slow client can't block
+    /// the others.
+    pub fn start(addr: &str) -> std::io::Result<Self> {
+        let listener = TcpListener::bind(addr)?;
+        let clients: Arc<Mutex<Vec<Sender<String>>>> = Arc::new(Mutex::new(Vec::new()));
+        let accept_clients = Arc::clone(&clients);
+        thread::spawn(move || accept_loop(listener, accept_clients));
+        Ok(Self { clients, addr: addr.to_string() })
+    }
+
+    pub fn addr(&self) -> &str {
+        &self.addr
+    }
+
+    /// Sends a shot-scored event to every currently connected client.
+    /// Connections whose writer thread has gone away are dropped.
+    pub fn broadcast_shot(&self, shot: &Shot) {
+        let event = ShotEvent {
+            number: shot.number,
+            value: shot.value,
+            x_mm: shot.x_mm,
+            y_mm: shot.y_mm,
+            is_x: shot.is_x,
+            timestamp: shot.timestamp,
+        };
+        let Ok(json) = serde_json::to_string(&event) else { return };
+        let Ok(mut clients) = self.clients.lock() else { return };
+        clients.retain(|tx| tx.send(json.clone()).is_ok());
+    }
+}
+
+fn accept_loop(listener: TcpListener, clients: Arc<Mutex<Vec<Sender<String>>>>) {
+    for stream in listener.incoming() {
+        let Ok(stream) = stream else { continue };
+        let Ok(mut socket) = tungstenite::accept(stream) else { continue };
+        let (tx, rx) = mpsc::channel::<String>();
+        if let Ok(mut clients) = clients.lock() {
+            clients.push(tx);
+        }
+        thread::spawn(move || {
+            for message in rx {
+                if socket.send(Message::Text(message)).is_err() {
+                    break;
+                }
+            }
+        });
+    }
+}